@@ -1,7 +1,7 @@
 import React, { useMemo, useState, useEffect } from "react";
 import { WalletMultiButton } from "@solana/wallet-adapter-react-ui";
 import { useWallet, useConnection } from "@solana/wallet-adapter-react";
-import { LocalDataSource } from "./lib/data";
+import { LocalDataSource, createChainDataSource } from "./lib/data";
 import { Transaction } from "@solana/web3.js";
 import { createMemoInstruction } from "@solana/spl-memo";
 import { WebBundlr } from "@bundlr-network/client";
@@ -362,7 +362,7 @@ export default function App() {
         const userId = publicKey.toBase58();
         const [snap, rew] = await Promise.all([
           LocalDataSource.getHealthSnapshot(userId),
-          LocalDataSource.getRewards(userId),
+          createChainDataSource(connection).getRewards(userId),
         ]);
 
         if (!cancelled) {
@@ -379,7 +379,7 @@ export default function App() {
     return () => {
       cancelled = true;
     };
-  }, [preview, connected, publicKey]);
+  }, [preview, connected, publicKey, connection]);
 
   const handleAiSend = async () => {
     if (!aiInput.trim()) return;
@@ -0,0 +1,13242 @@
+/**
+ * Program IDL in camelCase format in order to be used in JS/TS.
+ *
+ * Note that this is only a type helper and is not the actual IDL. The original
+ * IDL can be found at `target/idl/healthkey_protocol.json`.
+ */
+export type HealthkeyProtocol = {
+  "address": "2aPJ91YqkdpSTucNwBxGa42uwoHUCdhx6A4qeBkBrNkJ",
+  "metadata": {
+    "name": "healthkeyProtocol",
+    "version": "0.1.0",
+    "spec": "0.1.0",
+    "description": "Created with Anchor"
+  },
+  "instructions": [
+    {
+      "name": "abandonGoal",
+      "discriminator": [
+        43,
+        29,
+        240,
+        225,
+        177,
+        16,
+        216,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "goal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "goal.id",
+                "account": "goal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addHealthRecord",
+      "docs": [
+        "Anchor a new health record blob to the profile at the next free index."
+      ],
+      "discriminator": [
+        63,
+        170,
+        214,
+        44,
+        34,
+        36,
+        92,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "healthRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  101,
+                  97,
+                  108,
+                  116,
+                  104,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "user_profile.record_count",
+                "account": "userProfile"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "record",
+          "type": {
+            "defined": {
+              "name": "healthRecordInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addOracle",
+      "discriminator": [
+        185,
+        165,
+        165,
+        167,
+        208,
+        207,
+        55,
+        35
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "oracle",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "addRewardIssuer",
+      "discriminator": [
+        176,
+        49,
+        106,
+        138,
+        212,
+        63,
+        85,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "issuer",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "claimAttestedReward",
+      "docs": [
+        "Pay out for activity attested by a registered oracle. The transaction",
+        "must carry an Ed25519 program instruction, immediately before this one,",
+        "verifying the oracle's signature over the serialized attestation."
+      ],
+      "discriminator": [
+        169,
+        246,
+        253,
+        53,
+        242,
+        230,
+        75,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardTable",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  116,
+                  97,
+                  98,
+                  108,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "rewardLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "claimReceipt",
+          "writable": true
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "attestation",
+          "type": {
+            "defined": {
+              "name": "activityAttestation"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimChallengePrize",
+      "docs": [
+        "Pay a winner their prize (or, if nobody won, refund the entry fee)",
+        "and close their entry."
+      ],
+      "discriminator": [
+        196,
+        54,
+        188,
+        105,
+        157,
+        34,
+        145,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challenge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge.creator",
+                "account": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "challenge.id",
+                "account": "challenge"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeEntry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  95,
+                  101,
+                  110,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "challengeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "participantTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "participant"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimStakingRewards",
+      "docs": [
+        "Pay out the caller's accrued staking rewards from the staking reward",
+        "vault."
+      ],
+      "discriminator": [
+        229,
+        141,
+        170,
+        69,
+        111,
+        94,
+        6,
+        72
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakingPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "stakeAccount"
+          ]
+        },
+        {
+          "name": "ownerTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "rewardVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "stakingPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimVested",
+      "docs": [
+        "Release everything vested so far to the beneficiary's ATA."
+      ],
+      "discriminator": [
+        208,
+        190,
+        166,
+        114,
+        203,
+        225,
+        140,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingSchedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "account",
+                "path": "vesting_schedule.id",
+                "account": "vestingSchedule"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vestingSchedule"
+          ]
+        },
+        {
+          "name": "vestingVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingSchedule"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "beneficiaryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeChallenge",
+      "docs": [
+        "Close a settled challenge once every entry is closed, or once the",
+        "claim deadline has passed, sweeping what is left in its vault",
+        "(rounding dust plus any unclaimed payouts) to the creator and",
+        "returning the rent of both accounts."
+      ],
+      "discriminator": [
+        29,
+        156,
+        109,
+        17,
+        41,
+        99,
+        71,
+        236
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challenge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge.creator",
+                "account": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "challenge.id",
+                "account": "challenge"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "challengeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "challenge"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeChallengeEntry",
+      "docs": [
+        "Return the rent of a losing entry once the challenge is settled, or of",
+        "any entry whose payout went unclaimed past the claim deadline.",
+        "Callable by anyone; rent goes back to the participant."
+      ],
+      "discriminator": [
+        99,
+        93,
+        194,
+        51,
+        32,
+        234,
+        197,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "challenge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge.creator",
+                "account": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "challenge.id",
+                "account": "challenge"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeEntry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  95,
+                  101,
+                  110,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "relations": [
+            "challengeEntry"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeClaimReceipt",
+      "docs": [
+        "Reclaim the rent of a receipt whose period is older than the claim",
+        "horizon. Claims for such periods are rejected anyway, so dropping the",
+        "receipt cannot reopen a double payment. Callable by anyone; rent goes",
+        "back to the user who paid for the receipt."
+      ],
+      "discriminator": [
+        86,
+        202,
+        240,
+        236,
+        35,
+        20,
+        157,
+        183
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "claimReceipt",
+          "writable": true
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "relations": [
+            "claimReceipt"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExpiredGrant",
+      "docs": [
+        "Close a grant that has expired or has no uses left. Callable by",
+        "anyone; rent goes back to the profile owner."
+      ],
+      "discriminator": [
+        82,
+        127,
+        36,
+        174,
+        61,
+        234,
+        149,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accessGrant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115,
+                  95,
+                  103,
+                  114,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "access_grant.grantee",
+                "account": "accessGrant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeGoal",
+      "docs": [
+        "Reclaim the rent of a goal that is settled, abandoned, or past its",
+        "window without having been settled."
+      ],
+      "discriminator": [
+        253,
+        46,
+        15,
+        56,
+        118,
+        220,
+        140,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "goal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "goal.id",
+                "account": "goal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeKeyEnvelope",
+      "docs": [
+        "Delete a key envelope, e.g. one left stale by `rotate_record_key`."
+      ],
+      "discriminator": [
+        26,
+        195,
+        230,
+        69,
+        157,
+        216,
+        120,
+        243
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "keyEnvelope",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeUserProfile",
+      "docs": [
+        "Delete the profile and the program-owned accounts belonging to its",
+        "authority, returning all rent to the authority. Child accounts are",
+        "passed as writable remaining accounts, and every account counted in",
+        "`child_count` (health records, goals, access grants, ...) must be among",
+        "them.",
+        "",
+        "Claim receipts are not children here: they are replay protection and",
+        "are reclaimed through `close_claim_receipt` once they expire."
+      ],
+      "discriminator": [
+        242,
+        80,
+        248,
+        79,
+        81,
+        251,
+        65,
+        113
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createChallenge",
+      "docs": [
+        "Open a challenge that users join by paying `input.entry_fee` into the",
+        "challenge's vault before it starts."
+      ],
+      "discriminator": [
+        170,
+        244,
+        47,
+        1,
+        1,
+        15,
+        173,
+        239
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challenge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "challengeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "input",
+          "type": {
+            "defined": {
+              "name": "challengeInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createGoal",
+      "discriminator": [
+        229,
+        63,
+        42,
+        239,
+        1,
+        226,
+        219,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "goal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "user_profile.goal_count",
+                "account": "userProfile"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "input",
+          "type": {
+            "defined": {
+              "name": "goalInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createRewardPool",
+      "docs": [
+        "Open a sponsored reward campaign with its own mint, vault, budget and",
+        "issuers. The pool's vault ATA is owned by the pool PDA."
+      ],
+      "discriminator": [
+        199,
+        136,
+        155,
+        69,
+        28,
+        136,
+        237,
+        214
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "poolVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "rewardPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "input",
+          "type": {
+            "defined": {
+              "name": "rewardPoolInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createVesting",
+      "docs": [
+        "Lock `input.total_amount` from the vault into an escrow owned by a new",
+        "vesting schedule for `beneficiary`."
+      ],
+      "discriminator": [
+        135,
+        184,
+        171,
+        156,
+        197,
+        162,
+        246,
+        44
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "vestingSchedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingSchedule"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "beneficiary",
+          "type": "pubkey"
+        },
+        {
+          "name": "input",
+          "type": {
+            "defined": {
+              "name": "vestingInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "emergencyWithdraw",
+      "docs": [
+        "Move `amount` out of the vault to the treasury's ATA. Works while",
+        "paused, so funds can be secured after a key compromise."
+      ],
+      "discriminator": [
+        239,
+        45,
+        203,
+        64,
+        150,
+        73,
+        218,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "treasury",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "emergencyWithdrawPool",
+      "docs": [
+        "Move `amount` out of a reward pool's vault to the treasury's ATA for",
+        "the pool's mint. Admin only, and like `emergency_withdraw` it works",
+        "while paused."
+      ],
+      "discriminator": [
+        115,
+        146,
+        27,
+        72,
+        206,
+        31,
+        88,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "reward_pool.id",
+                "account": "rewardPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "rewardPool"
+          ]
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "treasury",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "poolVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "rewardPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "fundRewardPool",
+      "docs": [
+        "Deposit `amount` of the pool's mint into its vault. Anyone may fund."
+      ],
+      "discriminator": [
+        85,
+        49,
+        108,
+        245,
+        204,
+        70,
+        243,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "rewardPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "reward_pool.id",
+                "account": "rewardPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "rewardPool"
+          ]
+        },
+        {
+          "name": "funder",
+          "signer": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "poolVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "rewardPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "fundStakingRewards",
+      "docs": [
+        "Deposit `amount` of the protocol mint into the staking reward vault.",
+        "Emissions only accrue against funded rewards, so time spent unfunded",
+        "earns nothing retroactively. Anyone may fund."
+      ],
+      "discriminator": [
+        107,
+        76,
+        182,
+        217,
+        248,
+        168,
+        136,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakingPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "funder",
+          "signer": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "rewardVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "stakingPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "fundVault",
+      "docs": [
+        "Deposit `amount` of the reward mint from the funder's ATA into the",
+        "vault, creating the vault's ATA on first use. Anyone may fund."
+      ],
+      "discriminator": [
+        26,
+        33,
+        207,
+        242,
+        119,
+        108,
+        134,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "funder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "grantAccess",
+      "docs": [
+        "Record the user's consent for `grantee` to access the records in",
+        "`scope` for `purpose` until `expires_at`. `mode` can further limit the",
+        "grant to a number of reads or a single export."
+      ],
+      "discriminator": [
+        66,
+        88,
+        87,
+        113,
+        39,
+        22,
+        27,
+        165
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accessGrant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115,
+                  95,
+                  103,
+                  114,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "arg",
+                "path": "grantee"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "grantee",
+          "type": "pubkey"
+        },
+        {
+          "name": "scope",
+          "type": {
+            "defined": {
+              "name": "grantScope"
+            }
+          }
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        },
+        {
+          "name": "purpose",
+          "type": {
+            "defined": {
+              "name": "purposeCode"
+            }
+          }
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": {
+              "name": "grantMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeAccessLog",
+      "docs": [
+        "Create the profile's access log ahead of time. Otherwise the first",
+        "`record_access` creates it at the grantee's expense."
+      ],
+      "discriminator": [
+        146,
+        237,
+        88,
+        78,
+        102,
+        193,
+        138,
+        216
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accessLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeProtocol",
+      "docs": [
+        "Create the singleton protocol config. The signer must be the",
+        "program's upgrade authority; it becomes the admin and `mint` becomes",
+        "the only mint the vault will pay out."
+      ],
+      "discriminator": [
+        188,
+        233,
+        252,
+        106,
+        134,
+        146,
+        202,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programData",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  23,
+                  105,
+                  16,
+                  46,
+                  142,
+                  95,
+                  9,
+                  154,
+                  247,
+                  85,
+                  130,
+                  30,
+                  130,
+                  237,
+                  31,
+                  118,
+                  55,
+                  20,
+                  226,
+                  56,
+                  239,
+                  241,
+                  153,
+                  26,
+                  122,
+                  184,
+                  153,
+                  128,
+                  151,
+                  127,
+                  0,
+                  235
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                2,
+                168,
+                246,
+                145,
+                78,
+                136,
+                161,
+                176,
+                226,
+                16,
+                21,
+                62,
+                247,
+                99,
+                174,
+                43,
+                0,
+                194,
+                185,
+                61,
+                22,
+                193,
+                36,
+                210,
+                192,
+                83,
+                122,
+                16,
+                4,
+                128,
+                0,
+                0
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "issuers",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "limits",
+          "type": {
+            "defined": {
+              "name": "rewardLimits"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeStakingPool",
+      "docs": [
+        "Create the staking reward accumulator and its reward vault, streaming",
+        "`emission_rate` tokens per second to stakers pro rata for as long as",
+        "`fund_staking_rewards` deposits last."
+      ],
+      "discriminator": [
+        231,
+        155,
+        216,
+        76,
+        185,
+        211,
+        34,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakingPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "rewardVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "stakingPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "emissionRate",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeUserProfile",
+      "discriminator": [
+        192,
+        144,
+        204,
+        140,
+        113,
+        25,
+        59,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "arweaveHash",
+          "type": "string"
+        },
+        {
+          "name": "storage",
+          "type": {
+            "defined": {
+              "name": "storageKind"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "joinChallenge",
+      "docs": [
+        "Escrow the entry fee and register the caller as a participant."
+      ],
+      "discriminator": [
+        41,
+        104,
+        214,
+        73,
+        32,
+        168,
+        76,
+        79
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challenge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge.creator",
+                "account": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "challenge.id",
+                "account": "challenge"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeEntry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  95,
+                  101,
+                  110,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "challengeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "participantTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recordAccess",
+      "docs": [
+        "Called by a grantee each time it accesses a record. Consumes one use",
+        "of a read-limited or one-time-export grant, appends an entry to the",
+        "profile's access log (creating it on first use, paid by the grantee)",
+        "and emits `RecordAccessed`."
+      ],
+      "discriminator": [
+        64,
+        187,
+        29,
+        123,
+        147,
+        64,
+        218,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_profile.authority",
+                "account": "userProfile"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accessGrant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115,
+                  95,
+                  103,
+                  114,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "grantee"
+              }
+            ]
+          }
+        },
+        {
+          "name": "healthRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  101,
+                  97,
+                  108,
+                  116,
+                  104,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "health_record.index",
+                "account": "healthRecord"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accessLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              }
+            ]
+          }
+        },
+        {
+          "name": "grantee",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recordChallengeResult",
+      "docs": [
+        "Mark a participant as having reached the target, on the strength of",
+        "an oracle attestation (Ed25519 instruction immediately before this",
+        "one). Anyone may relay it once the challenge has ended."
+      ],
+      "discriminator": [
+        63,
+        20,
+        155,
+        50,
+        120,
+        178,
+        216,
+        112
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challenge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge.creator",
+                "account": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "challenge.id",
+                "account": "challenge"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeEntry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  95,
+                  101,
+                  110,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge"
+              },
+              {
+                "kind": "arg",
+                "path": "attestation.participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "attestation",
+          "type": {
+            "defined": {
+              "name": "challengeAttestation"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeOracle",
+      "discriminator": [
+        60,
+        93,
+        51,
+        197,
+        182,
+        42,
+        170,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "oracle",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "removeRewardIssuer",
+      "discriminator": [
+        52,
+        204,
+        12,
+        208,
+        104,
+        31,
+        150,
+        249
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "issuer",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "revokeAccess",
+      "docs": [
+        "Withdraw consent by closing the grant."
+      ],
+      "discriminator": [
+        106,
+        128,
+        38,
+        169,
+        103,
+        238,
+        102,
+        147
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accessGrant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115,
+                  95,
+                  103,
+                  114,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "access_grant.grantee",
+                "account": "accessGrant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeVesting",
+      "docs": [
+        "Stop vesting now and return the unvested remainder to the vault. What",
+        "has already vested stays claimable."
+      ],
+      "discriminator": [
+        12,
+        252,
+        252,
+        168,
+        39,
+        101,
+        98,
+        9
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "vestingSchedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vesting_schedule.beneficiary",
+                "account": "vestingSchedule"
+              },
+              {
+                "kind": "account",
+                "path": "vesting_schedule.id",
+                "account": "vestingSchedule"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingSchedule"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rewardUser",
+      "docs": [
+        "Transfer tokens from a reward pool's vault to the user's ATA.",
+        "Automatically creates the user's ATA if it doesn't exist.",
+        "Must be co-signed by a key that is both a protocol issuer and one of",
+        "the pool's issuers, inside the pool's window and within its budget",
+        "and epoch cap. Pools paying in the protocol mint are also bounded by",
+        "the per-user and global epoch caps. Each (pool, user, metric, period)",
+        "can only be paid once. `reason` is tallied on the user's",
+        "`RewardLedger` when the pool pays in the protocol mint. For such pools,",
+        "passing the user's stake account boosts `amount` by their staking",
+        "multiplier."
+      ],
+      "discriminator": [
+        185,
+        88,
+        102,
+        210,
+        188,
+        153,
+        92,
+        249
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "reward_pool.id",
+                "account": "rewardPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "rewardPool"
+          ]
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "issuer",
+          "signer": true
+        },
+        {
+          "name": "rewardLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "claimReceipt",
+          "writable": true
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "poolVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "rewardPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "metric",
+          "type": {
+            "defined": {
+              "name": "metricKind"
+            }
+          }
+        },
+        {
+          "name": "period",
+          "type": "i64"
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "rewardReason"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "rotateRecordKey",
+      "docs": [
+        "Point the record at a blob re-encrypted under a fresh key and bump its",
+        "key version. Envelopes for older versions no longer match the record,",
+        "so grantees whose access was revoked cannot decrypt the new blob."
+      ],
+      "discriminator": [
+        127,
+        9,
+        126,
+        60,
+        13,
+        210,
+        147,
+        83
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "healthRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  101,
+                  97,
+                  108,
+                  116,
+                  104,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "health_record.index",
+                "account": "healthRecord"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "storageUri",
+          "type": "string"
+        },
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setEmissionRate",
+      "docs": [
+        "Change the emission rate. Rewards accrued so far keep the old rate."
+      ],
+      "discriminator": [
+        65,
+        212,
+        116,
+        79,
+        181,
+        93,
+        85,
+        117
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakingPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "emissionRate",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setGoalReward",
+      "docs": [
+        "Set the amount paid by `settle_goal` for a completed goal."
+      ],
+      "discriminator": [
+        153,
+        108,
+        159,
+        170,
+        168,
+        90,
+        249,
+        165
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setGuardian",
+      "docs": [
+        "Hand the pause switch to a new guardian key."
+      ],
+      "discriminator": [
+        147,
+        243,
+        50,
+        121,
+        154,
+        164,
+        50,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "docs": [
+        "Stop (or resume) every instruction that moves tokens out of the vault."
+      ],
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "guardian",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPoolIssuers",
+      "docs": [
+        "Replace the set of keys allowed to pay out of a pool. A key only pays",
+        "while it is also listed in the protocol's issuers."
+      ],
+      "discriminator": [
+        74,
+        102,
+        93,
+        153,
+        115,
+        237,
+        0,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "reward_pool.id",
+                "account": "rewardPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "issuers",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRewardTable",
+      "docs": [
+        "Replace the reward table used to price attested activity. Creates the",
+        "table on first use."
+      ],
+      "discriminator": [
+        36,
+        167,
+        205,
+        202,
+        254,
+        169,
+        211,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardTable",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  116,
+                  97,
+                  98,
+                  108,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "rewardTier"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setStakingParams",
+      "docs": [
+        "Replace the staking cooldown and multiplier curve."
+      ],
+      "discriminator": [
+        10,
+        122,
+        191,
+        49,
+        166,
+        65,
+        71,
+        207
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "stakingParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTreasury",
+      "docs": [
+        "Change the wallet whose ATA receives `emergency_withdraw` funds."
+      ],
+      "discriminator": [
+        57,
+        97,
+        196,
+        95,
+        195,
+        206,
+        106,
+        136
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "settleChallenge",
+      "docs": [
+        "Fix each winner's share of the pot once the result window has",
+        "closed. With no winners, every participant gets their fee back.",
+        "Anyone may settle."
+      ],
+      "discriminator": [
+        242,
+        58,
+        232,
+        150,
+        127,
+        199,
+        11,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "challenge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenge.creator",
+                "account": "challenge"
+              },
+              {
+                "kind": "account",
+                "path": "challenge.id",
+                "account": "challenge"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleGoal",
+      "docs": [
+        "Settle a goal whose window has closed against an oracle attestation of",
+        "how many cadence periods met the target. A goal that met every period",
+        "is marked completed and pays `ProtocolConfig::goal_reward`; otherwise",
+        "it is marked failed. Either way it can only be settled once."
+      ],
+      "discriminator": [
+        240,
+        49,
+        46,
+        252,
+        188,
+        251,
+        98,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userProfile",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "goal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "goal.id",
+                "account": "goal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "rewardLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "attestation",
+          "type": {
+            "defined": {
+              "name": "goalAttestation"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "shareRecordKey",
+      "docs": [
+        "Store the record's symmetric key wrapped for a grantee: an X25519",
+        "ephemeral public key plus the AEAD ciphertext of the key under the",
+        "shared secret with the grantee. Requires a live grant covering the",
+        "record. Re-sharing overwrites the grantee's previous envelope."
+      ],
+      "discriminator": [
+        5,
+        119,
+        1,
+        215,
+        69,
+        41,
+        163,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "healthRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  101,
+                  97,
+                  108,
+                  116,
+                  104,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "health_record.index",
+                "account": "healthRecord"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accessGrant",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115,
+                  95,
+                  103,
+                  114,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "access_grant.grantee",
+                "account": "accessGrant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "keyEnvelope",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  107,
+                  101,
+                  121,
+                  95,
+                  101,
+                  110,
+                  118,
+                  101,
+                  108,
+                  111,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "healthRecord"
+              },
+              {
+                "kind": "account",
+                "path": "access_grant.grantee",
+                "account": "accessGrant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "ephemeralPubkey",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "wrappedKey",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "stake",
+      "docs": [
+        "Lock `amount` of the protocol mint in the caller's stake vault.",
+        "Adding to an existing stake moves `staked_since` forward in proportion,",
+        "so the time bonus can't be gamed by topping up an old, small stake."
+      ],
+      "discriminator": [
+        206,
+        176,
+        202,
+        18,
+        200,
+        209,
+        179,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakingPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "stakeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "stakeAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "ownerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "supersedeHealthRecord",
+      "docs": [
+        "Anchor a corrected version of an existing record. The old record is",
+        "kept, pointing at its replacement through `superseded_by`."
+      ],
+      "discriminator": [
+        234,
+        195,
+        216,
+        229,
+        62,
+        89,
+        249,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "previousRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  101,
+                  97,
+                  108,
+                  116,
+                  104,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "previous_record.index",
+                "account": "healthRecord"
+              }
+            ]
+          }
+        },
+        {
+          "name": "healthRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  101,
+                  97,
+                  108,
+                  116,
+                  104,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "user_profile.record_count",
+                "account": "userProfile"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "record",
+          "type": {
+            "defined": {
+              "name": "healthRecordInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "unstake",
+      "docs": [
+        "Move `amount` out of the active stake and start the cooldown. The",
+        "tokens stop counting towards the multiplier immediately."
+      ],
+      "discriminator": [
+        90,
+        95,
+        107,
+        42,
+        205,
+        124,
+        50,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakingPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "signer": true,
+          "relations": [
+            "stakeAccount"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateGoal",
+      "docs": [
+        "Change a goal's terms. Only allowed before the goal window opens, so",
+        "the target cannot be moved once progress is being measured."
+      ],
+      "discriminator": [
+        143,
+        149,
+        87,
+        239,
+        70,
+        114,
+        214,
+        221
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "goal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userProfile"
+              },
+              {
+                "kind": "account",
+                "path": "goal.id",
+                "account": "goal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "update",
+          "type": {
+            "defined": {
+              "name": "goalUpdate"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRewardLimits",
+      "docs": [
+        "Replace the emission limits. The running global window is kept, so",
+        "lowering a cap takes effect immediately for the current epoch. The",
+        "claim horizon can only shrink: receipts past it may already have been",
+        "closed, and widening it again would make those periods payable twice."
+      ],
+      "discriminator": [
+        254,
+        19,
+        65,
+        151,
+        26,
+        148,
+        146,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "limits",
+          "type": {
+            "defined": {
+              "name": "rewardLimits"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateUserProfile",
+      "docs": [
+        "Replace any of the profile's fields. `None` keeps the current value."
+      ],
+      "discriminator": [
+        79,
+        75,
+        114,
+        130,
+        68,
+        123,
+        180,
+        11
+      ],
+      "accounts": [
+        {
+          "name": "userProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "userProfile"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "arweaveHash",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "storage",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "storageKind"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdraw",
+      "docs": [
+        "Return every unstaked token whose cooldown has elapsed."
+      ],
+      "discriminator": [
+        183,
+        18,
+        70,
+        156,
+        148,
+        109,
+        161,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "stakeAccount"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "stakeAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "ownerTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "accessGrant",
+      "discriminator": [
+        167,
+        55,
+        184,
+        237,
+        74,
+        242,
+        0,
+        109
+      ]
+    },
+    {
+      "name": "accessLog",
+      "discriminator": [
+        99,
+        147,
+        234,
+        225,
+        53,
+        121,
+        161,
+        122
+      ]
+    },
+    {
+      "name": "challenge",
+      "discriminator": [
+        119,
+        250,
+        161,
+        121,
+        119,
+        81,
+        22,
+        208
+      ]
+    },
+    {
+      "name": "challengeEntry",
+      "discriminator": [
+        54,
+        28,
+        66,
+        148,
+        62,
+        53,
+        230,
+        43
+      ]
+    },
+    {
+      "name": "claimReceipt",
+      "discriminator": [
+        223,
+        233,
+        11,
+        229,
+        124,
+        165,
+        207,
+        28
+      ]
+    },
+    {
+      "name": "goal",
+      "discriminator": [
+        163,
+        66,
+        166,
+        245,
+        130,
+        131,
+        207,
+        26
+      ]
+    },
+    {
+      "name": "healthRecord",
+      "discriminator": [
+        75,
+        125,
+        187,
+        175,
+        128,
+        179,
+        45,
+        90
+      ]
+    },
+    {
+      "name": "keyEnvelope",
+      "discriminator": [
+        157,
+        194,
+        169,
+        228,
+        45,
+        178,
+        30,
+        117
+      ]
+    },
+    {
+      "name": "protocolConfig",
+      "discriminator": [
+        207,
+        91,
+        250,
+        28,
+        152,
+        179,
+        215,
+        209
+      ]
+    },
+    {
+      "name": "rewardLedger",
+      "discriminator": [
+        122,
+        67,
+        36,
+        15,
+        3,
+        191,
+        51,
+        235
+      ]
+    },
+    {
+      "name": "rewardPool",
+      "discriminator": [
+        134,
+        121,
+        197,
+        211,
+        133,
+        154,
+        82,
+        32
+      ]
+    },
+    {
+      "name": "rewardTable",
+      "discriminator": [
+        6,
+        175,
+        24,
+        191,
+        102,
+        94,
+        27,
+        71
+      ]
+    },
+    {
+      "name": "stakeAccount",
+      "discriminator": [
+        80,
+        158,
+        67,
+        124,
+        50,
+        189,
+        192,
+        255
+      ]
+    },
+    {
+      "name": "stakingPool",
+      "discriminator": [
+        203,
+        19,
+        214,
+        220,
+        220,
+        154,
+        24,
+        102
+      ]
+    },
+    {
+      "name": "userProfile",
+      "discriminator": [
+        32,
+        37,
+        119,
+        205,
+        179,
+        180,
+        13,
+        194
+      ]
+    },
+    {
+      "name": "vestingSchedule",
+      "discriminator": [
+        130,
+        200,
+        173,
+        148,
+        39,
+        75,
+        243,
+        147
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accessGranted",
+      "discriminator": [
+        21,
+        212,
+        83,
+        192,
+        198,
+        26,
+        62,
+        185
+      ]
+    },
+    {
+      "name": "accessLogCreated",
+      "discriminator": [
+        25,
+        110,
+        58,
+        77,
+        42,
+        101,
+        147,
+        74
+      ]
+    },
+    {
+      "name": "accessRevoked",
+      "discriminator": [
+        200,
+        160,
+        73,
+        43,
+        201,
+        165,
+        43,
+        159
+      ]
+    },
+    {
+      "name": "challengeClosed",
+      "discriminator": [
+        228,
+        8,
+        12,
+        154,
+        182,
+        5,
+        139,
+        91
+      ]
+    },
+    {
+      "name": "challengeCreated",
+      "discriminator": [
+        166,
+        178,
+        174,
+        178,
+        11,
+        172,
+        98,
+        243
+      ]
+    },
+    {
+      "name": "challengeEntryClosed",
+      "discriminator": [
+        186,
+        81,
+        0,
+        195,
+        68,
+        153,
+        48,
+        141
+      ]
+    },
+    {
+      "name": "challengeJoined",
+      "discriminator": [
+        254,
+        42,
+        73,
+        56,
+        99,
+        74,
+        6,
+        15
+      ]
+    },
+    {
+      "name": "challengePrizeClaimed",
+      "discriminator": [
+        174,
+        88,
+        158,
+        44,
+        32,
+        77,
+        131,
+        222
+      ]
+    },
+    {
+      "name": "challengeResultRecorded",
+      "discriminator": [
+        13,
+        19,
+        178,
+        78,
+        245,
+        253,
+        195,
+        196
+      ]
+    },
+    {
+      "name": "challengeSettled",
+      "discriminator": [
+        96,
+        70,
+        232,
+        36,
+        45,
+        207,
+        169,
+        122
+      ]
+    },
+    {
+      "name": "claimReceiptClosed",
+      "discriminator": [
+        253,
+        101,
+        92,
+        9,
+        109,
+        176,
+        169,
+        100
+      ]
+    },
+    {
+      "name": "emergencyWithdrawal",
+      "discriminator": [
+        225,
+        77,
+        96,
+        117,
+        149,
+        211,
+        83,
+        71
+      ]
+    },
+    {
+      "name": "emissionRateUpdated",
+      "discriminator": [
+        78,
+        205,
+        103,
+        154,
+        166,
+        83,
+        119,
+        253
+      ]
+    },
+    {
+      "name": "goalClosed",
+      "discriminator": [
+        47,
+        14,
+        46,
+        23,
+        156,
+        29,
+        151,
+        147
+      ]
+    },
+    {
+      "name": "goalCreated",
+      "discriminator": [
+        40,
+        74,
+        205,
+        224,
+        20,
+        51,
+        92,
+        239
+      ]
+    },
+    {
+      "name": "goalRewardUpdated",
+      "discriminator": [
+        232,
+        45,
+        208,
+        95,
+        155,
+        213,
+        26,
+        47
+      ]
+    },
+    {
+      "name": "goalStatusChanged",
+      "discriminator": [
+        113,
+        5,
+        88,
+        208,
+        186,
+        122,
+        242,
+        216
+      ]
+    },
+    {
+      "name": "goalUpdated",
+      "discriminator": [
+        249,
+        249,
+        50,
+        204,
+        145,
+        41,
+        41,
+        3
+      ]
+    },
+    {
+      "name": "guardianUpdated",
+      "discriminator": [
+        31,
+        95,
+        81,
+        24,
+        90,
+        9,
+        246,
+        32
+      ]
+    },
+    {
+      "name": "healthRecordAdded",
+      "discriminator": [
+        245,
+        155,
+        12,
+        239,
+        176,
+        185,
+        106,
+        220
+      ]
+    },
+    {
+      "name": "keyEnvelopeClosed",
+      "discriminator": [
+        13,
+        11,
+        34,
+        181,
+        91,
+        34,
+        29,
+        11
+      ]
+    },
+    {
+      "name": "oracleAdded",
+      "discriminator": [
+        48,
+        0,
+        207,
+        33,
+        20,
+        56,
+        215,
+        219
+      ]
+    },
+    {
+      "name": "oracleRemoved",
+      "discriminator": [
+        62,
+        112,
+        125,
+        81,
+        128,
+        93,
+        194,
+        96
+      ]
+    },
+    {
+      "name": "pauseToggled",
+      "discriminator": [
+        105,
+        215,
+        89,
+        53,
+        198,
+        232,
+        136,
+        161
+      ]
+    },
+    {
+      "name": "profileCreated",
+      "discriminator": [
+        134,
+        233,
+        199,
+        153,
+        77,
+        206,
+        128,
+        94
+      ]
+    },
+    {
+      "name": "profileDeleted",
+      "discriminator": [
+        133,
+        245,
+        179,
+        127,
+        17,
+        36,
+        92,
+        80
+      ]
+    },
+    {
+      "name": "profileUpdated",
+      "discriminator": [
+        186,
+        248,
+        62,
+        98,
+        112,
+        98,
+        161,
+        252
+      ]
+    },
+    {
+      "name": "protocolInitialized",
+      "discriminator": [
+        173,
+        122,
+        168,
+        254,
+        9,
+        118,
+        76,
+        132
+      ]
+    },
+    {
+      "name": "recordAccessed",
+      "discriminator": [
+        122,
+        197,
+        83,
+        151,
+        200,
+        243,
+        18,
+        12
+      ]
+    },
+    {
+      "name": "recordKeyRotated",
+      "discriminator": [
+        42,
+        115,
+        25,
+        248,
+        180,
+        210,
+        211,
+        88
+      ]
+    },
+    {
+      "name": "recordKeyShared",
+      "discriminator": [
+        38,
+        50,
+        5,
+        68,
+        96,
+        103,
+        47,
+        143
+      ]
+    },
+    {
+      "name": "rewardIssuerAdded",
+      "discriminator": [
+        53,
+        159,
+        81,
+        60,
+        145,
+        190,
+        97,
+        81
+      ]
+    },
+    {
+      "name": "rewardIssuerRemoved",
+      "discriminator": [
+        131,
+        64,
+        225,
+        9,
+        247,
+        71,
+        138,
+        135
+      ]
+    },
+    {
+      "name": "rewardLimitsUpdated",
+      "discriminator": [
+        52,
+        159,
+        241,
+        220,
+        43,
+        244,
+        93,
+        42
+      ]
+    },
+    {
+      "name": "rewardPaid",
+      "discriminator": [
+        132,
+        160,
+        190,
+        117,
+        215,
+        177,
+        19,
+        95
+      ]
+    },
+    {
+      "name": "rewardPoolCreated",
+      "discriminator": [
+        96,
+        16,
+        23,
+        67,
+        111,
+        199,
+        213,
+        166
+      ]
+    },
+    {
+      "name": "rewardPoolEmergencyWithdrawal",
+      "discriminator": [
+        191,
+        195,
+        176,
+        96,
+        80,
+        154,
+        115,
+        209
+      ]
+    },
+    {
+      "name": "rewardPoolFunded",
+      "discriminator": [
+        86,
+        253,
+        182,
+        175,
+        22,
+        199,
+        5,
+        198
+      ]
+    },
+    {
+      "name": "rewardPoolIssuersUpdated",
+      "discriminator": [
+        62,
+        223,
+        16,
+        155,
+        45,
+        119,
+        234,
+        5
+      ]
+    },
+    {
+      "name": "rewardTableUpdated",
+      "discriminator": [
+        184,
+        240,
+        44,
+        130,
+        77,
+        8,
+        157,
+        164
+      ]
+    },
+    {
+      "name": "stakeWithdrawn",
+      "discriminator": [
+        33,
+        120,
+        159,
+        58,
+        140,
+        255,
+        174,
+        79
+      ]
+    },
+    {
+      "name": "staked",
+      "discriminator": [
+        11,
+        146,
+        45,
+        205,
+        230,
+        58,
+        213,
+        240
+      ]
+    },
+    {
+      "name": "stakingParamsUpdated",
+      "discriminator": [
+        30,
+        178,
+        46,
+        118,
+        43,
+        59,
+        147,
+        28
+      ]
+    },
+    {
+      "name": "stakingRewardsClaimed",
+      "discriminator": [
+        251,
+        169,
+        50,
+        96,
+        7,
+        92,
+        141,
+        137
+      ]
+    },
+    {
+      "name": "stakingRewardsFunded",
+      "discriminator": [
+        186,
+        208,
+        216,
+        161,
+        231,
+        1,
+        34,
+        90
+      ]
+    },
+    {
+      "name": "treasuryUpdated",
+      "discriminator": [
+        80,
+        239,
+        54,
+        168,
+        43,
+        38,
+        85,
+        145
+      ]
+    },
+    {
+      "name": "unstakeStarted",
+      "discriminator": [
+        3,
+        239,
+        138,
+        233,
+        85,
+        246,
+        119,
+        173
+      ]
+    },
+    {
+      "name": "vaultFunded",
+      "discriminator": [
+        192,
+        119,
+        245,
+        193,
+        55,
+        223,
+        195,
+        50
+      ]
+    },
+    {
+      "name": "vestedClaimed",
+      "discriminator": [
+        90,
+        39,
+        80,
+        199,
+        242,
+        108,
+        89,
+        46
+      ]
+    },
+    {
+      "name": "vestingCreated",
+      "discriminator": [
+        181,
+        223,
+        229,
+        220,
+        204,
+        6,
+        169,
+        125
+      ]
+    },
+    {
+      "name": "vestingRevoked",
+      "discriminator": [
+        215,
+        148,
+        193,
+        127,
+        237,
+        245,
+        90,
+        75
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidAmount",
+      "msg": "Amount must be greater than zero"
+    },
+    {
+      "code": 6001,
+      "name": "unauthorized",
+      "msg": "Signer is not the protocol admin"
+    },
+    {
+      "code": 6002,
+      "name": "invalidMint",
+      "msg": "Mint does not match the protocol mint"
+    },
+    {
+      "code": 6003,
+      "name": "unauthorizedIssuer",
+      "msg": "Signer is not an authorized reward issuer"
+    },
+    {
+      "code": 6004,
+      "name": "tooManyIssuers",
+      "msg": "Too many reward issuers"
+    },
+    {
+      "code": 6005,
+      "name": "issuerAlreadyExists",
+      "msg": "Reward issuer is already registered"
+    },
+    {
+      "code": 6006,
+      "name": "issuerNotFound",
+      "msg": "Reward issuer is not registered"
+    },
+    {
+      "code": 6007,
+      "name": "invalidEpochDuration",
+      "msg": "Epoch duration must be greater than zero"
+    },
+    {
+      "code": 6008,
+      "name": "invalidRewardLimits",
+      "msg": "Per-user cap cannot exceed the global cap"
+    },
+    {
+      "code": 6009,
+      "name": "userRewardCapExceeded",
+      "msg": "Reward would exceed the user's cap for this epoch"
+    },
+    {
+      "code": 6010,
+      "name": "globalRewardCapExceeded",
+      "msg": "Reward would exceed the global cap for this epoch"
+    },
+    {
+      "code": 6011,
+      "name": "mathOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6012,
+      "name": "tooManyOracles",
+      "msg": "Too many oracles"
+    },
+    {
+      "code": 6013,
+      "name": "oracleAlreadyExists",
+      "msg": "Oracle is already registered"
+    },
+    {
+      "code": 6014,
+      "name": "oracleNotFound",
+      "msg": "Oracle is not registered"
+    },
+    {
+      "code": 6015,
+      "name": "tooManyRewardTiers",
+      "msg": "Too many reward tiers"
+    },
+    {
+      "code": 6016,
+      "name": "attestationUserMismatch",
+      "msg": "Attestation was issued for a different user"
+    },
+    {
+      "code": 6017,
+      "name": "missingEd25519instruction",
+      "msg": "Expected an Ed25519 signature instruction before this one"
+    },
+    {
+      "code": 6018,
+      "name": "invalidEd25519instruction",
+      "msg": "Malformed Ed25519 signature instruction"
+    },
+    {
+      "code": 6019,
+      "name": "attestationMismatch",
+      "msg": "Signed message does not match the attestation"
+    },
+    {
+      "code": 6020,
+      "name": "unknownOracle",
+      "msg": "Attestation was not signed by a registered oracle"
+    },
+    {
+      "code": 6021,
+      "name": "noRewardForActivity",
+      "msg": "Attested activity does not qualify for a reward"
+    },
+    {
+      "code": 6022,
+      "name": "invalidClaimHorizon",
+      "msg": "Claim horizon must be greater than zero"
+    },
+    {
+      "code": 6023,
+      "name": "alreadyClaimed",
+      "msg": "Reward for this period has already been claimed"
+    },
+    {
+      "code": 6024,
+      "name": "periodInFuture",
+      "msg": "Period has not started yet"
+    },
+    {
+      "code": 6025,
+      "name": "claimWindowExpired",
+      "msg": "Period is older than the claim horizon"
+    },
+    {
+      "code": 6026,
+      "name": "claimReceiptStillActive",
+      "msg": "Claim receipt is still within the claim horizon"
+    },
+    {
+      "code": 6027,
+      "name": "storageIdTooLong",
+      "msg": "Storage pointer is too long"
+    },
+    {
+      "code": 6028,
+      "name": "invalidArweaveTxId",
+      "msg": "Not a valid 43-character base64url Arweave transaction ID"
+    },
+    {
+      "code": 6029,
+      "name": "invalidIpfsCid",
+      "msg": "Not a valid base32 CIDv1"
+    },
+    {
+      "code": 6030,
+      "name": "invalidChildAccount",
+      "msg": "Account is not a closable child of this profile"
+    },
+    {
+      "code": 6031,
+      "name": "childAccountMismatch",
+      "msg": "Child account belongs to a different user"
+    },
+    {
+      "code": 6032,
+      "name": "rewardLedgerWindowOpen",
+      "msg": "Reward ledger cannot be closed until its epoch ends"
+    },
+    {
+      "code": 6033,
+      "name": "profileHasOpenAccounts",
+      "msg": "All child accounts must be closed with the profile"
+    },
+    {
+      "code": 6034,
+      "name": "invalidStorageUri",
+      "msg": "Storage URI must start with ar:// or ipfs://"
+    },
+    {
+      "code": 6035,
+      "name": "recordedInFuture",
+      "msg": "Record timestamp is in the future"
+    },
+    {
+      "code": 6036,
+      "name": "recordAlreadySuperseded",
+      "msg": "Record has already been superseded"
+    },
+    {
+      "code": 6037,
+      "name": "invalidUnitForMetric",
+      "msg": "Unit is not valid for this metric"
+    },
+    {
+      "code": 6038,
+      "name": "invalidGoalTarget",
+      "msg": "Goal target must be greater than zero"
+    },
+    {
+      "code": 6039,
+      "name": "invalidGoalWindow",
+      "msg": "Goal must end in the future and span at least one cadence period"
+    },
+    {
+      "code": 6040,
+      "name": "goalNotActive",
+      "msg": "Goal is not active"
+    },
+    {
+      "code": 6041,
+      "name": "goalAlreadyStarted",
+      "msg": "Goal window has already started"
+    },
+    {
+      "code": 6042,
+      "name": "goalWindowOpen",
+      "msg": "Goal window has not ended yet"
+    },
+    {
+      "code": 6043,
+      "name": "goalStillActive",
+      "msg": "Goal is still active"
+    },
+    {
+      "code": 6044,
+      "name": "emptyGrantScope",
+      "msg": "Grant must cover at least one metric or record"
+    },
+    {
+      "code": 6045,
+      "name": "grantScopeTooLarge",
+      "msg": "Grant scope lists too many metrics or records"
+    },
+    {
+      "code": 6046,
+      "name": "invalidGrantExpiry",
+      "msg": "Grant expiry must be in the future"
+    },
+    {
+      "code": 6047,
+      "name": "invalidWrappedKey",
+      "msg": "Wrapped key is empty or too long"
+    },
+    {
+      "code": 6048,
+      "name": "grantExpired",
+      "msg": "Access grant has expired"
+    },
+    {
+      "code": 6049,
+      "name": "recordNotInGrantScope",
+      "msg": "Record is not covered by the access grant"
+    },
+    {
+      "code": 6050,
+      "name": "invalidGrantMode",
+      "msg": "Read-limited grants need at least one read; exports must start unused"
+    },
+    {
+      "code": 6051,
+      "name": "grantExhausted",
+      "msg": "Access grant has no uses left"
+    },
+    {
+      "code": 6052,
+      "name": "grantStillActive",
+      "msg": "Access grant is still active"
+    },
+    {
+      "code": 6053,
+      "name": "protocolPaused",
+      "msg": "Protocol is paused"
+    },
+    {
+      "code": 6054,
+      "name": "invalidTreasury",
+      "msg": "Account is not the configured treasury"
+    },
+    {
+      "code": 6055,
+      "name": "invalidPoolWindow",
+      "msg": "Reward pool must end after it starts"
+    },
+    {
+      "code": 6056,
+      "name": "poolNotActive",
+      "msg": "Reward pool is not active"
+    },
+    {
+      "code": 6057,
+      "name": "poolBudgetExceeded",
+      "msg": "Reward pool budget exceeded"
+    },
+    {
+      "code": 6058,
+      "name": "invalidVestingSchedule",
+      "msg": "Vesting cliff must fall within a non-empty vesting period"
+    },
+    {
+      "code": 6059,
+      "name": "nothingToClaim",
+      "msg": "Nothing has vested since the last claim"
+    },
+    {
+      "code": 6060,
+      "name": "vestingAlreadyRevoked",
+      "msg": "Vesting schedule was already revoked"
+    },
+    {
+      "code": 6061,
+      "name": "invalidStakingParams",
+      "msg": "Invalid staking parameters"
+    },
+    {
+      "code": 6062,
+      "name": "insufficientStake",
+      "msg": "Not enough tokens staked"
+    },
+    {
+      "code": 6063,
+      "name": "nothingToWithdraw",
+      "msg": "Nothing is waiting to be withdrawn"
+    },
+    {
+      "code": 6064,
+      "name": "cooldownActive",
+      "msg": "Unstake cooldown has not elapsed"
+    },
+    {
+      "code": 6065,
+      "name": "invalidChallenge",
+      "msg": "Invalid challenge terms"
+    },
+    {
+      "code": 6066,
+      "name": "challengeAlreadyStarted",
+      "msg": "Challenge has already started"
+    },
+    {
+      "code": 6067,
+      "name": "challengeFull",
+      "msg": "Challenge is full"
+    },
+    {
+      "code": 6068,
+      "name": "challengeNotEnded",
+      "msg": "Challenge has not ended yet"
+    },
+    {
+      "code": 6069,
+      "name": "challengeNotOpen",
+      "msg": "Challenge is not open"
+    },
+    {
+      "code": 6070,
+      "name": "challengeResultWindowClosed",
+      "msg": "Challenge results can no longer be recorded"
+    },
+    {
+      "code": 6071,
+      "name": "challengeResultWindowOpen",
+      "msg": "Challenge results can still be recorded"
+    },
+    {
+      "code": 6072,
+      "name": "challengeTargetNotReached",
+      "msg": "Attested value does not reach the challenge target"
+    },
+    {
+      "code": 6073,
+      "name": "challengeResultAlreadyRecorded",
+      "msg": "Challenge result already recorded"
+    },
+    {
+      "code": 6074,
+      "name": "challengeNotSettled",
+      "msg": "Challenge has not been settled"
+    },
+    {
+      "code": 6075,
+      "name": "notAChallengeWinner",
+      "msg": "Participant did not reach the challenge target"
+    },
+    {
+      "code": 6076,
+      "name": "poolEpochCapExceeded",
+      "msg": "Reward pool epoch cap exceeded"
+    },
+    {
+      "code": 6077,
+      "name": "misalignedPeriod",
+      "msg": "Claim period must start on a CLAIM_PERIOD boundary"
+    },
+    {
+      "code": 6078,
+      "name": "claimHorizonIncreased",
+      "msg": "Claim horizon cannot be increased"
+    },
+    {
+      "code": 6079,
+      "name": "challengeEntryClaimable",
+      "msg": "Challenge entry is owed a payout; claim it instead"
+    },
+    {
+      "code": 6080,
+      "name": "challengeEntriesOpen",
+      "msg": "Challenge still has open entries"
+    },
+    {
+      "code": 6081,
+      "name": "challengeClaimWindowClosed",
+      "msg": "Challenge claim window has closed"
+    }
+  ],
+  "types": [
+    {
+      "name": "accessGrant",
+      "docs": [
+        "Consent receipt: the profile owner allows `grantee` to access the records",
+        "covered by `scope` until `expires_at`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "grantee",
+            "type": "pubkey"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": {
+                "name": "grantScope"
+              }
+            }
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "purpose",
+            "type": {
+              "defined": {
+                "name": "purposeCode"
+              }
+            }
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": {
+                "name": "grantMode"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "accessGranted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "grantee",
+            "type": "pubkey"
+          },
+          {
+            "name": "grant",
+            "type": "pubkey"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": {
+                "name": "grantScope"
+              }
+            }
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "purpose",
+            "type": {
+              "defined": {
+                "name": "purposeCode"
+              }
+            }
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": {
+                "name": "grantMode"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "accessLog",
+      "docs": [
+        "Append-only ring buffer of third-party accesses to a profile's records."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalEntries",
+            "docs": [
+              "Entries ever written; the next one goes to",
+              "`total_entries % ACCESS_LOG_CAPACITY`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "accessLogEntry"
+                  }
+                },
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "accessLogCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "accessLog",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "accessLogEntry",
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "grantee",
+            "type": "pubkey"
+          },
+          {
+            "name": "grant",
+            "type": "pubkey"
+          },
+          {
+            "name": "accessedAt",
+            "type": "i64"
+          },
+          {
+            "name": "recordIndex",
+            "type": "u64"
+          },
+          {
+            "name": "metric",
+            "docs": [
+              "`MetricKind` discriminant of the accessed record."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "purpose",
+            "docs": [
+              "`PurposeCode` discriminant of the grant."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "accessRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "grantee",
+            "type": "pubkey"
+          },
+          {
+            "name": "grant",
+            "type": "pubkey"
+          },
+          {
+            "name": "expired",
+            "docs": [
+              "`true` when closed by `close_expired_grant` rather than the owner."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "activityAttestation",
+      "docs": [
+        "Activity claim signed off-chain by a registered oracle."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "period",
+            "docs": [
+              "Unix timestamp of the start of the period the activity covers, a",
+              "multiple of `CLAIM_PERIOD`."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cadence",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "daily"
+          },
+          {
+            "name": "weekly"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challenge",
+      "docs": [
+        "A community challenge: participants pay `entry_fee` into a vault owned",
+        "by this PDA, and those attested to reach `target` between `start_ts` and",
+        "`end_ts` split the pot."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "docs": [
+              "Total over the window a participant must reach."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxParticipants",
+            "type": "u32"
+          },
+          {
+            "name": "participantCount",
+            "type": "u32"
+          },
+          {
+            "name": "winnerCount",
+            "type": "u32"
+          },
+          {
+            "name": "pot",
+            "docs": [
+              "Entry fees collected."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "prize",
+            "docs": [
+              "Paid to each claimant, fixed by `settle_challenge`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "closedEntries",
+            "docs": [
+              "Entries closed so far, by claiming or by `close_challenge_entry`."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "challengeStatus"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeAttestation",
+      "docs": [
+        "Oracle statement of a participant's total over a challenge window. It",
+        "repeats the challenge's terms so the oracle signs exactly the challenge",
+        "being judged."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "participant",
+            "type": "pubkey"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "value",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "swept",
+            "docs": [
+              "Rounding remainder and unclaimed payouts swept to the creator."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxParticipants",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeEntry",
+      "docs": [
+        "One participant's seat in a challenge. Closed when they claim, or, for a",
+        "loser, by `close_challenge_entry` after settlement."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "participant",
+            "type": "pubkey"
+          },
+          {
+            "name": "reachedTarget",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeEntryClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "participant",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxParticipants",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeJoined",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "participant",
+            "type": "pubkey"
+          },
+          {
+            "name": "pot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengePrizeClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "participant",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "refund",
+            "docs": [
+              "`true` when nobody won and the entry fee was returned."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeResultRecorded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "participant",
+            "type": "pubkey"
+          },
+          {
+            "name": "value",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "pubkey"
+          },
+          {
+            "name": "winnerCount",
+            "type": "u32"
+          },
+          {
+            "name": "prize",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "open"
+          },
+          {
+            "name": "settled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimReceipt",
+      "docs": [
+        "Marks a (user, metric, period) as paid, by the protocol vault or by one",
+        "reward pool."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "`Pubkey::default()` for payouts from the protocol vault."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "period",
+            "type": "i64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "claimedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimReceiptClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "period",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "comparator",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "atLeast"
+          },
+          {
+            "name": "atMost"
+          }
+        ]
+      }
+    },
+    {
+      "name": "emergencyWithdrawal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "emissionRateUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "emissionRate",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goal",
+      "docs": [
+        "A measurable target the user commits to, e.g. \"at least 8,000 steps",
+        "daily between `start_ts` and `end_ts`\"."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "type": "u64"
+          },
+          {
+            "name": "comparator",
+            "type": {
+              "defined": {
+                "name": "comparator"
+              }
+            }
+          },
+          {
+            "name": "cadence",
+            "docs": [
+              "Period the target applies to."
+            ],
+            "type": {
+              "defined": {
+                "name": "cadence"
+              }
+            }
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "goalStatus"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalAttestation",
+      "docs": [
+        "Oracle statement of a goal's outcome. It repeats the goal's terms so the",
+        "oracle signs exactly the goal that is being settled."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "goal",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "type": "u64"
+          },
+          {
+            "name": "comparator",
+            "type": {
+              "defined": {
+                "name": "comparator"
+              }
+            }
+          },
+          {
+            "name": "cadence",
+            "type": {
+              "defined": {
+                "name": "cadence"
+              }
+            }
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "periodsMet",
+            "docs": [
+              "Cadence periods within the window in which the target was met."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "goal",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "goal",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "type": "u64"
+          },
+          {
+            "name": "comparator",
+            "type": {
+              "defined": {
+                "name": "comparator"
+              }
+            }
+          },
+          {
+            "name": "cadence",
+            "type": {
+              "defined": {
+                "name": "cadence"
+              }
+            }
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "type": "u64"
+          },
+          {
+            "name": "comparator",
+            "type": {
+              "defined": {
+                "name": "comparator"
+              }
+            }
+          },
+          {
+            "name": "cadence",
+            "type": {
+              "defined": {
+                "name": "cadence"
+              }
+            }
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalRewardUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "completed"
+          },
+          {
+            "name": "abandoned"
+          },
+          {
+            "name": "failed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalStatusChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "goal",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "goalStatus"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalUpdate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "target",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "comparator",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "comparator"
+                }
+              }
+            }
+          },
+          {
+            "name": "cadence",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "cadence"
+                }
+              }
+            }
+          },
+          {
+            "name": "startTs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "endTs",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "goal",
+            "type": "pubkey"
+          },
+          {
+            "name": "target",
+            "type": "u64"
+          },
+          {
+            "name": "comparator",
+            "type": {
+              "defined": {
+                "name": "comparator"
+              }
+            }
+          },
+          {
+            "name": "cadence",
+            "type": {
+              "defined": {
+                "name": "cadence"
+              }
+            }
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "grantMode",
+      "docs": [
+        "How an access grant is used up. Every mode is also bounded by",
+        "`AccessGrant::expires_at`."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "expiring"
+          },
+          {
+            "name": "limitedReads",
+            "fields": [
+              {
+                "name": "remaining",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "oneTimeExport",
+            "fields": [
+              {
+                "name": "used",
+                "type": "bool"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "grantScope",
+      "docs": [
+        "Records covered by a grant: every record of one of `metrics`, plus the",
+        "records listed in `record_indices`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metrics",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "metricKind"
+                }
+              }
+            }
+          },
+          {
+            "name": "recordIndices",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "guardianUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "healthRecord",
+      "docs": [
+        "On-chain anchor for one off-chain health record blob."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "storageUri",
+            "docs": [
+              "`ar://<tx id>` or `ipfs://<cid>`."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "docs": [
+              "SHA-256 of the blob's contents."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "recordedAt",
+            "docs": [
+              "When the measurement was taken, as reported by the user."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "schemaVersion",
+            "type": "u16"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "supersededBy",
+            "docs": [
+              "Index of the record that replaced this one."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "keyVersion",
+            "docs": [
+              "Version of the symmetric key the blob is encrypted under. Only",
+              "`KeyEnvelope`s with the same version can decrypt it."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "healthRecordAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "record",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "storageUri",
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "supersedes",
+            "docs": [
+              "Index of the record this one corrects, if any."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "healthRecordInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "storageUri",
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "recordedAt",
+            "type": "i64"
+          },
+          {
+            "name": "schemaVersion",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "keyEnvelope",
+      "docs": [
+        "A record's symmetric key, wrapped for one grantee with X25519 + AEAD."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "record",
+            "type": "pubkey"
+          },
+          {
+            "name": "grantee",
+            "type": "pubkey"
+          },
+          {
+            "name": "keyVersion",
+            "docs": [
+              "`HealthRecord::key_version` the wrapped key belongs to."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "ephemeralPubkey",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "wrappedKey",
+            "type": "bytes"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "keyEnvelopeClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "record",
+            "type": "pubkey"
+          },
+          {
+            "name": "grantee",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "metricKind",
+      "docs": [
+        "Canonical taxonomy of health metrics shared by records, attestations and",
+        "the reward table. The discriminant is used in PDA seeds, so variants must",
+        "only ever be appended."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "steps"
+          },
+          {
+            "name": "sleepMinutes"
+          },
+          {
+            "name": "heartRate"
+          },
+          {
+            "name": "bloodPressure"
+          },
+          {
+            "name": "glucose"
+          },
+          {
+            "name": "weight"
+          },
+          {
+            "name": "workout"
+          },
+          {
+            "name": "custom"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseToggled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "guardian",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "profileCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "storage",
+            "type": {
+              "defined": {
+                "name": "storageKind"
+              }
+            }
+          },
+          {
+            "name": "arweaveHash",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "profileDeleted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "storage",
+            "type": {
+              "defined": {
+                "name": "storageKind"
+              }
+            }
+          },
+          {
+            "name": "arweaveHash",
+            "docs": [
+              "Storage pointer off-chain indexes should purge."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "recordUris",
+            "docs": [
+              "`storage_uri`s of the health records closed with the profile, whose",
+              "blobs should be purged as well."
+            ],
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "closedAccounts",
+            "docs": [
+              "Number of child accounts closed alongside the profile."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "deletedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "profileUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "storage",
+            "type": {
+              "defined": {
+                "name": "storageKind"
+              }
+            }
+          },
+          {
+            "name": "arweaveHash",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "issuers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "oracles",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "limits",
+            "type": {
+              "defined": {
+                "name": "rewardLimits"
+              }
+            }
+          },
+          {
+            "name": "epochStart",
+            "docs": [
+              "Start of the epoch `epoch_distributed` refers to."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "epochDistributed",
+            "docs": [
+              "Total paid out by the vault during the current epoch."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "goalReward",
+            "docs": [
+              "Paid by `settle_goal` for each completed goal."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "May flip `paused`; defaults to the admin."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Owner of the token account `emergency_withdraw` pays into."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "While set, nothing can be paid out of the vault except through",
+              "`emergency_withdraw`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "totalFunded",
+            "docs": [
+              "Lifetime deposits made through `fund_vault`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalDistributed",
+            "docs": [
+              "Lifetime payouts to users in the protocol mint, across all reward",
+              "paths including reward pools. Vesting grants count when locked, less",
+              "whatever a revocation returns. Staking rewards are funded separately",
+              "and tracked on `StakingPool`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalWithdrawn",
+            "docs": [
+              "Lifetime `emergency_withdraw` outflows."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "staking",
+            "type": {
+              "defined": {
+                "name": "stakingParams"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "limits",
+            "type": {
+              "defined": {
+                "name": "rewardLimits"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "purposeCode",
+      "docs": [
+        "Why the grantee is being given access."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "treatment"
+          },
+          {
+            "name": "research"
+          },
+          {
+            "name": "coaching"
+          },
+          {
+            "name": "insurance"
+          },
+          {
+            "name": "emergency"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recordAccessed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profile",
+            "type": "pubkey"
+          },
+          {
+            "name": "grantee",
+            "type": "pubkey"
+          },
+          {
+            "name": "grant",
+            "type": "pubkey"
+          },
+          {
+            "name": "record",
+            "type": "pubkey"
+          },
+          {
+            "name": "recordIndex",
+            "type": "u64"
+          },
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "purpose",
+            "type": {
+              "defined": {
+                "name": "purposeCode"
+              }
+            }
+          },
+          {
+            "name": "accessedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recordKeyRotated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "record",
+            "type": "pubkey"
+          },
+          {
+            "name": "keyVersion",
+            "type": "u32"
+          },
+          {
+            "name": "storageUri",
+            "docs": [
+              "Blob re-encrypted under the new key."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "recordKeyShared",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "record",
+            "type": "pubkey"
+          },
+          {
+            "name": "grantee",
+            "type": "pubkey"
+          },
+          {
+            "name": "keyVersion",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardIssuerAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "issuer",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardIssuerRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "issuer",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardLedger",
+      "docs": [
+        "Per-user emission tracking for the current reward window, plus the",
+        "user's reward history."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "windowStart",
+            "type": "i64"
+          },
+          {
+            "name": "claimedInWindow",
+            "type": "u64"
+          },
+          {
+            "name": "earnedByReason",
+            "docs": [
+              "Lifetime totals, indexed by `RewardReason as usize`."
+            ],
+            "type": {
+              "array": [
+                "u64",
+                6
+              ]
+            }
+          },
+          {
+            "name": "monthStart",
+            "docs": [
+              "Start of the UTC calendar month `earned_this_month` covers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "earnedThisMonth",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epochDuration",
+            "docs": [
+              "Length of a reward window in seconds (86_400 for daily caps)."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "userEpochCap",
+            "docs": [
+              "Maximum a single user may receive per epoch."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "globalEpochCap",
+            "docs": [
+              "Maximum the vault may pay out across all users per epoch."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimHorizon",
+            "docs": [
+              "How long after a period starts it can still be claimed, in seconds.",
+              "Receipts for older periods may be garbage-collected."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardLimitsUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limits",
+            "type": {
+              "defined": {
+                "name": "rewardLimits"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardPaid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "rewardReason"
+              }
+            }
+          },
+          {
+            "name": "issuer",
+            "docs": [
+              "Issuer that signed `reward_user`, or the oracle behind an attestation."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pool",
+            "docs": [
+              "Pool paid from, or `None` for the protocol vault."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardPool",
+      "docs": [
+        "A separately funded reward campaign, e.g. for one sponsor. Pays out of",
+        "its own vault ATA, owned by the pool PDA."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "issuers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "budget",
+            "docs": [
+              "Maximum the pool will ever pay out."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "distributed",
+            "type": "u64"
+          },
+          {
+            "name": "epochCap",
+            "docs": [
+              "Maximum the pool may pay out per protocol epoch."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "epochStart",
+            "docs": [
+              "Start of the epoch `epoch_distributed` refers to."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "epochDistributed",
+            "type": "u64"
+          },
+          {
+            "name": "totalFunded",
+            "docs": [
+              "Lifetime deposits made through `fund_reward_pool`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalWithdrawn",
+            "docs": [
+              "Lifetime `emergency_withdraw_pool` outflows."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardPoolCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "budget",
+            "type": "u64"
+          },
+          {
+            "name": "epochCap",
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardPoolEmergencyWithdrawal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardPoolFunded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "funder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardPoolInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "budget",
+            "type": "u64"
+          },
+          {
+            "name": "epochCap",
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "issuers",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardPoolIssuersUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "issuers",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardReason",
+      "docs": [
+        "Why tokens left the vault. Only ever append variants: the discriminant",
+        "indexes `RewardLedger::earned_by_reason`."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "dailyLog"
+          },
+          {
+            "name": "goalCompletion"
+          },
+          {
+            "name": "dataContribution"
+          },
+          {
+            "name": "challengeWin"
+          },
+          {
+            "name": "referral"
+          },
+          {
+            "name": "manualGrant"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardTable",
+      "docs": [
+        "Maps attested activity to a payout. For a given metric the highest tier",
+        "whose `min_value` the attested value reaches is paid."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tiers",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "rewardTier"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardTableUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tiers",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "rewardTier"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metric",
+            "type": {
+              "defined": {
+                "name": "metricKind"
+              }
+            }
+          },
+          {
+            "name": "unit",
+            "type": {
+              "defined": {
+                "name": "unit"
+              }
+            }
+          },
+          {
+            "name": "minValue",
+            "type": "u64"
+          },
+          {
+            "name": "reward",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeAccount",
+      "docs": [
+        "A user's staked protocol tokens, held in an ATA owned by this PDA."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Actively staked; counts towards the multiplier."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakedSince",
+            "docs": [
+              "Amount-weighted time the active stake was deposited."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "pendingWithdrawal",
+            "docs": [
+              "Unstaked and cooling down."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unlockTs",
+            "docs": [
+              "When `pending_withdrawal` becomes withdrawable."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rewardPerTokenPaid",
+            "docs": [
+              "`StakingPool::reward_per_token_stored` at the last checkpoint."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "accruedRewards",
+            "docs": [
+              "Staking rewards earned but not yet claimed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "staked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "totalStaked",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakingParams",
+      "docs": [
+        "Cooldown and multiplier curve for staking. A stake of at least",
+        "`full_bonus_amount` held for `full_bonus_duration` earns the full",
+        "`max_bonus_bps` on top of every `reward_user` payout; smaller or younger",
+        "stakes earn proportionally less."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cooldown",
+            "docs": [
+              "Seconds between `unstake` and `withdraw`."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxBonusBps",
+            "type": "u64"
+          },
+          {
+            "name": "fullBonusAmount",
+            "type": "u64"
+          },
+          {
+            "name": "fullBonusDuration",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakingParamsUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "params",
+            "type": {
+              "defined": {
+                "name": "stakingParams"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakingPool",
+      "docs": [
+        "Streams `emission_rate` tokens per second to stakers in proportion to",
+        "their active stake. Each staker's share is the growth of",
+        "`reward_per_token_stored` since their own checkpoint, so claims are O(1)",
+        "however many stakers there are. Rewards are paid from the pool's own",
+        "reward vault ATA and never accrue beyond what was deposited into it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardPerTokenStored",
+            "docs": [
+              "Cumulative rewards per staked token, scaled by",
+              "`REWARD_PER_TOKEN_PRECISION`."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "lastUpdateTs",
+            "type": "i64"
+          },
+          {
+            "name": "emissionRate",
+            "type": "u64"
+          },
+          {
+            "name": "totalStaked",
+            "type": "u64"
+          },
+          {
+            "name": "totalFunded",
+            "docs": [
+              "Lifetime deposits made through `fund_staking_rewards`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalEmitted",
+            "docs": [
+              "Rewards allocated to stakers so far; never exceeds `total_funded`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakingRewardsClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakingRewardsFunded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "funder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "storageKind",
+      "docs": [
+        "Where the blob referenced by a profile lives."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "arweave"
+          },
+          {
+            "name": "ipfs"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasuryUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "unit",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "count"
+          },
+          {
+            "name": "minutes"
+          },
+          {
+            "name": "beatsPerMinute"
+          },
+          {
+            "name": "millimetersOfMercury"
+          },
+          {
+            "name": "milligramsPerDeciliter"
+          },
+          {
+            "name": "grams"
+          },
+          {
+            "name": "kilocalories"
+          },
+          {
+            "name": "custom"
+          }
+        ]
+      }
+    },
+    {
+      "name": "unstakeStarted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "unlockTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "arweaveHash",
+            "docs": [
+              "Arweave transaction ID, or a CIDv1 when `storage` is `Ipfs`."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "storage",
+            "type": {
+              "defined": {
+                "name": "storageKind"
+              }
+            }
+          },
+          {
+            "name": "recordCount",
+            "docs": [
+              "Index the next health record will be created at."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "childCount",
+            "docs": [
+              "Open child accounts (health records, goals, access grants, key",
+              "envelopes, the access log) that must be closed along with the profile."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "goalCount",
+            "docs": [
+              "Id the next goal will be created with."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultFunded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "funder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "totalFunded",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestedClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "cliffTs",
+            "type": "i64"
+          },
+          {
+            "name": "duration",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "cliffTs",
+            "type": "i64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "Seconds from `start_ts` until everything is vested."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "returned",
+            "docs": [
+              "Unvested amount sent back to the vault."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingSchedule",
+      "docs": [
+        "Tokens granted to `beneficiary` that unlock linearly between `start_ts`",
+        "and `start_ts + duration`, with nothing claimable before `cliff_ts`. The",
+        "tokens sit in an escrow ATA owned by this PDA."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "released",
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "i64"
+          },
+          {
+            "name": "cliffTs",
+            "type": "i64"
+          },
+          {
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "revokedAt",
+            "docs": [
+              "Vesting stops at this time; the unvested rest went back to the vault."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ]
+};
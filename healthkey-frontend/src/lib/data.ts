@@ -1,4 +1,9 @@
 // src/lib/data.ts
+import { BorshAccountsCoder, type Idl } from "@coral-xyz/anchor";
+import { Connection, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync, getMint } from "@solana/spl-token";
+import idl from "../idl/healthkey_protocol.json";
+
 export type HealthSnapshot = { steps: number; calories: number; sleepHrs: number; sparkline: number[] };
 export type Rewards = { balance: number; earnedThisMonth: number };
 
@@ -7,6 +12,8 @@ export interface DataSource {
   getRewards(pubkey: string): Promise<Rewards>;
 }
 
+export const PROGRAM_ID = new PublicKey("2aPJ91YqkdpSTucNwBxGa42uwoHUCdhx6A4qeBkBrNkJ");
+
 // src/lib/data.ts
 export const LocalDataSource = {
   async getHealthSnapshot(userId: string) {
@@ -26,3 +33,56 @@ export const LocalDataSource = {
     };
   },
 };
+
+// Decodes accounts by their IDL layout, so the reads below follow the
+// program's account structs as they change. Names are as in the raw IDL.
+const accountsCoder = new BorshAccountsCoder(idl as Idl);
+
+function utcMonthStart(date: Date): number {
+  return Date.UTC(date.getUTCFullYear(), date.getUTCMonth(), 1) / 1000;
+}
+
+// Reads the $HEALTH balance and the RewardLedger's monthly total from chain.
+// Health data still comes from LocalDataSource until records are wired up.
+export function createChainDataSource(connection: Connection, programId = PROGRAM_ID): DataSource {
+  const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("config")], programId);
+
+  return {
+    getHealthSnapshot: LocalDataSource.getHealthSnapshot,
+
+    async getRewards(userId: string) {
+      const user = new PublicKey(userId);
+      const [ledgerPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_ledger"), user.toBuffer()],
+        programId
+      );
+
+      const [config, ledger] = await connection.getMultipleAccountsInfo([configPda, ledgerPda]);
+      if (!config) return { balance: 0, earnedThisMonth: 0 };
+
+      const { mint } = accountsCoder.decode("ProtocolConfig", config.data);
+      const mintAccount = await connection.getAccountInfo(mint);
+      if (!mintAccount) return { balance: 0, earnedThisMonth: 0 };
+
+      // The ATA address depends on which token program owns the mint
+      // (classic Token or Token-2022).
+      const ata = getAssociatedTokenAddressSync(mint, user, false, mintAccount.owner);
+      const tokenAccount = await connection.getAccountInfo(ata);
+      const { decimals } = await getMint(connection, mint, undefined, mintAccount.owner);
+
+      const balance = tokenAccount
+        ? Number((await connection.getTokenAccountBalance(ata)).value.uiAmount ?? 0)
+        : 0;
+
+      let earnedThisMonth = 0;
+      if (ledger) {
+        const { month_start, earned_this_month } = accountsCoder.decode("RewardLedger", ledger.data);
+        if (month_start.toNumber() === utcMonthStart(new Date())) {
+          earnedThisMonth = Number(earned_this_month.toString()) / 10 ** decimals;
+        }
+      }
+
+      return { balance, earnedThisMonth };
+    },
+  };
+}
@@ -1,7 +1,8 @@
 import { Program, AnchorProvider, web3 } from "@coral-xyz/anchor";
 import idl from "../idl/healthkey_protocol.json";
+import type { HealthkeyProtocol } from "../idl/healthkey_protocol";
 
-export function getProgram(wallet: any): Program {
+export function getProgram(wallet: any): Program<HealthkeyProtocol> {
   const connection = new web3.Connection(web3.clusterApiUrl("devnet"));
   const provider = new AnchorProvider(connection, wallet, {
     preflightCommitment: "processed",
@@ -9,5 +10,5 @@ export function getProgram(wallet: any): Program {
 
   const programId = new web3.PublicKey("2aPJ91YqkdpSTucNwBxGa42uwoHUCdhx6A4qeBkBrNkJ");
 
-  return new Program(idl as any, provider);
+  return new Program<HealthkeyProtocol>(idl as HealthkeyProtocol, provider);
 }
@@ -1,122 +1,6658 @@
+// The IDL resize instruction `#[program]` generates calls the deprecated
+// `AccountInfo::realloc`, as does the instructions sysvar import below.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 declare_id!("2aPJ91YqkdpSTucNwBxGa42uwoHUCdhx6A4qeBkBrNkJ");
 
+/// Maximum number of keys allowed to co-sign `reward_user`.
+pub const MAX_REWARD_ISSUERS: usize = 8;
+/// Maximum number of oracle keys whose attestations are accepted.
+pub const MAX_ORACLES: usize = 8;
+/// Maximum number of tiers in the reward table.
+pub const MAX_REWARD_TIERS: usize = 32;
+/// Maximum length of a profile's storage pointer (Arweave tx ID or CIDv1).
+pub const MAX_STORAGE_ID_LEN: usize = 64;
+/// Maximum length of a health record's storage URI (`ar://…` or `ipfs://…`).
+pub const MAX_STORAGE_URI_LEN: usize = 72;
+/// Maximum number of metric kinds a single access grant can cover.
+pub const MAX_GRANT_METRICS: usize = 8;
+/// Maximum number of individual records a single access grant can cover.
+pub const MAX_GRANT_RECORDS: usize = 16;
+/// Maximum size of a wrapped record key (nonce + 32-byte key + AEAD tag).
+pub const MAX_WRAPPED_KEY_LEN: usize = 80;
+/// Number of entries kept in a profile's access log before the oldest are
+/// overwritten.
+pub const ACCESS_LOG_CAPACITY: usize = 64;
+/// Number of `RewardReason` variants, i.e. buckets in `RewardLedger`.
+pub const REWARD_REASON_COUNT: usize = 6;
+/// Length of a base64url-encoded Arweave transaction ID.
+pub const ARWEAVE_TX_ID_LEN: usize = 43;
+/// Basis points denominator for reward multipliers.
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// Largest staking bonus the admin may configure (i.e. at most 2x payouts).
+pub const MAX_STAKE_BONUS_BPS: u64 = 10_000;
+/// Fixed-point scale of `StakingPool::reward_per_token_stored`.
+pub const REWARD_PER_TOKEN_PRECISION: u128 = 1_000_000_000_000;
+/// Prefix of every message an oracle signs, so an attestation signature can
+/// never be replayed as a signature over some other payload.
+pub const ACTIVITY_ATTESTATION_DOMAIN: &[u8] = b"healthkey:activity:v1";
+/// Prefix of goal progress attestations.
+pub const GOAL_ATTESTATION_DOMAIN: &[u8] = b"healthkey:goal:v1";
+/// Prefix of challenge result attestations.
+pub const CHALLENGE_ATTESTATION_DOMAIN: &[u8] = b"healthkey:challenge:v1";
+/// Length of a claimable activity period. Claim periods must start on a
+/// multiple of this, so each day of activity has exactly one receipt.
+pub const CLAIM_PERIOD: i64 = 86_400;
+/// How long after a challenge ends oracle results may still be recorded.
+/// Only then can the challenge be settled.
+pub const CHALLENGE_RESULT_WINDOW: i64 = 3 * 86_400;
+/// How long after the result window closes payouts may be claimed. After
+/// that the creator may close the challenge and sweep whatever is unclaimed.
+pub const CHALLENGE_CLAIM_WINDOW: i64 = 30 * 86_400;
+/// Longest window a goal may span.
+pub const MAX_GOAL_DURATION: i64 = 366 * 86_400;
+
 #[program]
 pub mod healthkey_protocol {
     use super::*;
 
+    /// Create the singleton protocol config. The signer must be the
+    /// program's upgrade authority; it becomes the admin and `mint` becomes
+    /// the only mint the vault will pay out.
+    pub fn initialize_protocol(
+        ctx: Context<InitializeProtocol>,
+        issuers: Vec<Pubkey>,
+        limits: RewardLimits,
+    ) -> Result<()> {
+        validate_issuers(&issuers)?;
+        limits.validate()?;
+
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.mint = ctx.accounts.mint.key();
+        config.issuers = issuers;
+        config.limits = limits;
+        config.epoch_start = 0;
+        config.epoch_distributed = 0;
+        config.guardian = config.admin;
+        config.treasury = config.admin;
+        config.paused = false;
+        config.total_funded = 0;
+        config.total_distributed = 0;
+        config.total_withdrawn = 0;
+        config.staking = StakingParams::default();
+        config.bump = ctx.bumps.config;
+
+        emit_cpi!(ProtocolInitialized {
+            admin: config.admin,
+            mint: config.mint,
+            limits,
+        });
+        Ok(())
+    }
+
+    /// Replace the emission limits. The running global window is kept, so
+    /// lowering a cap takes effect immediately for the current epoch. The
+    /// claim horizon can only shrink: receipts past it may already have been
+    /// closed, and widening it again would make those periods payable twice.
+    pub fn update_reward_limits(
+        ctx: Context<UpdateProtocolConfig>,
+        limits: RewardLimits,
+    ) -> Result<()> {
+        limits.validate()?;
+        let config = &mut ctx.accounts.config;
+        require!(
+            limits.claim_horizon <= config.limits.claim_horizon,
+            ErrorCode::ClaimHorizonIncreased
+        );
+        config.limits = limits;
+        emit_cpi!(RewardLimitsUpdated { limits });
+        Ok(())
+    }
+
+    /// Replace the staking cooldown and multiplier curve.
+    pub fn set_staking_params(
+        ctx: Context<UpdateProtocolConfig>,
+        params: StakingParams,
+    ) -> Result<()> {
+        params.validate()?;
+        ctx.accounts.config.staking = params;
+        emit_cpi!(StakingParamsUpdated { params });
+        Ok(())
+    }
+
+    /// Set the amount paid by `settle_goal` for a completed goal.
+    pub fn set_goal_reward(ctx: Context<UpdateProtocolConfig>, amount: u64) -> Result<()> {
+        ctx.accounts.config.goal_reward = amount;
+        emit_cpi!(GoalRewardUpdated { amount });
+        Ok(())
+    }
+
+    pub fn add_reward_issuer(ctx: Context<UpdateProtocolConfig>, issuer: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_issuer(&issuer), ErrorCode::IssuerAlreadyExists);
+        require!(
+            config.issuers.len() < MAX_REWARD_ISSUERS,
+            ErrorCode::TooManyIssuers
+        );
+        config.issuers.push(issuer);
+        emit_cpi!(RewardIssuerAdded { issuer });
+        Ok(())
+    }
+
+    pub fn remove_reward_issuer(ctx: Context<UpdateProtocolConfig>, issuer: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let idx = config
+            .issuers
+            .iter()
+            .position(|k| *k == issuer)
+            .ok_or(ErrorCode::IssuerNotFound)?;
+        config.issuers.swap_remove(idx);
+        emit_cpi!(RewardIssuerRemoved { issuer });
+        Ok(())
+    }
+
+    pub fn add_oracle(ctx: Context<UpdateProtocolConfig>, oracle: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_oracle(&oracle), ErrorCode::OracleAlreadyExists);
+        require!(
+            config.oracles.len() < MAX_ORACLES,
+            ErrorCode::TooManyOracles
+        );
+        config.oracles.push(oracle);
+        emit_cpi!(OracleAdded { oracle });
+        Ok(())
+    }
+
+    pub fn remove_oracle(ctx: Context<UpdateProtocolConfig>, oracle: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let idx = config
+            .oracles
+            .iter()
+            .position(|k| *k == oracle)
+            .ok_or(ErrorCode::OracleNotFound)?;
+        config.oracles.swap_remove(idx);
+        emit_cpi!(OracleRemoved { oracle });
+        Ok(())
+    }
+
+    /// Hand the pause switch to a new guardian key.
+    pub fn set_guardian(ctx: Context<UpdateProtocolConfig>, guardian: Pubkey) -> Result<()> {
+        ctx.accounts.config.guardian = guardian;
+        emit_cpi!(GuardianUpdated { guardian });
+        Ok(())
+    }
+
+    /// Change the wallet whose ATA receives `emergency_withdraw` funds.
+    pub fn set_treasury(ctx: Context<UpdateProtocolConfig>, treasury: Pubkey) -> Result<()> {
+        ctx.accounts.config.treasury = treasury;
+        emit_cpi!(TreasuryUpdated { treasury });
+        Ok(())
+    }
+
+    /// Stop (or resume) every instruction that moves tokens out of the vault.
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.config.paused = paused;
+        emit_cpi!(PauseToggled {
+            paused,
+            guardian: ctx.accounts.guardian.key(),
+        });
+        Ok(())
+    }
+
+    /// Move `amount` out of the vault to the treasury's ATA. Works while
+    /// paused, so funds can be secured after a key compromise.
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        let config = &mut ctx.accounts.config;
+        config.total_withdrawn = config
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        transfer_from_vault(
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.treasury_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.token_program,
+            ctx.bumps.vault_authority,
+            amount,
+        )?;
+
+        emit_cpi!(EmergencyWithdrawal {
+            treasury: ctx.accounts.treasury.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Move `amount` out of a reward pool's vault to the treasury's ATA for
+    /// the pool's mint. Admin only, and like `emergency_withdraw` it works
+    /// while paused.
+    pub fn emergency_withdraw_pool(ctx: Context<EmergencyWithdrawPool>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        let pool = &mut ctx.accounts.reward_pool;
+        pool.total_withdrawn = pool
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        transfer_from_pool(
+            pool,
+            &ctx.accounts.pool_vault,
+            &ctx.accounts.treasury_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        emit_cpi!(RewardPoolEmergencyWithdrawal {
+            pool: pool.key(),
+            treasury: ctx.accounts.treasury.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Deposit `amount` of the reward mint from the funder's ATA into the
+    /// vault, creating the vault's ATA on first use. Anyone may fund.
+    pub fn fund_vault(ctx: Context<FundVault>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.funder_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let config = &mut ctx.accounts.config;
+        config.total_funded = config
+            .total_funded
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit_cpi!(VaultFunded {
+            funder: ctx.accounts.funder.key(),
+            amount,
+            total_funded: config.total_funded,
+        });
+        Ok(())
+    }
+
+    /// Replace the reward table used to price attested activity. Creates the
+    /// table on first use.
+    pub fn set_reward_table(ctx: Context<SetRewardTable>, tiers: Vec<RewardTier>) -> Result<()> {
+        require!(
+            tiers.len() <= MAX_REWARD_TIERS,
+            ErrorCode::TooManyRewardTiers
+        );
+        for tier in &tiers {
+            tier.metric.check_unit(tier.unit)?;
+        }
+        let table = &mut ctx.accounts.reward_table;
+        table.tiers = tiers.clone();
+        table.bump = ctx.bumps.reward_table;
+        emit_cpi!(RewardTableUpdated { tiers });
+        Ok(())
+    }
+
     pub fn initialize_user_profile(
         ctx: Context<InitializeUserProfile>,
         arweave_hash: String,
-        goal: String,
+        storage: StorageKind,
     ) -> Result<()> {
+        storage.validate_id(&arweave_hash)?;
+
         let profile = &mut ctx.accounts.user_profile;
         profile.authority = *ctx.accounts.authority.key;
         profile.arweave_hash = arweave_hash;
-        profile.goal = goal;
         profile.created_at = Clock::get()?.unix_timestamp;
+        profile.storage = storage;
+
+        emit_cpi!(ProfileCreated {
+            authority: profile.authority,
+            profile: profile.key(),
+            storage: profile.storage,
+            arweave_hash: profile.arweave_hash.clone(),
+        });
         Ok(())
     }
 
-    /// Transfer $HEALTH from the PDA-owned vault token account to the user's ATA.
-    /// Automatically creates the user's ATA if it doesn't exist.
-    pub fn reward_user(ctx: Context<RewardUser>, amount: u64) -> Result<()> {
-        require!(amount > 0, ErrorCode::InvalidAmount);
+    /// Replace any of the profile's fields. `None` keeps the current value.
+    pub fn update_user_profile(
+        ctx: Context<UpdateUserProfile>,
+        arweave_hash: Option<String>,
+        storage: Option<StorageKind>,
+    ) -> Result<()> {
+        let profile = &mut ctx.accounts.user_profile;
+        if let Some(arweave_hash) = arweave_hash {
+            profile.arweave_hash = arweave_hash;
+        }
+        if let Some(storage) = storage {
+            profile.storage = storage;
+        }
+        // Re-check the pointer against the (possibly new) storage kind.
+        profile.storage.validate_id(&profile.arweave_hash)?;
+
+        emit_cpi!(ProfileUpdated {
+            authority: profile.authority,
+            profile: profile.key(),
+            storage: profile.storage,
+            arweave_hash: profile.arweave_hash.clone(),
+        });
+        Ok(())
+    }
+
+    /// Delete the profile and the program-owned accounts belonging to its
+    /// authority, returning all rent to the authority. Child accounts are
+    /// passed as writable remaining accounts, and every account counted in
+    /// `child_count` (health records, goals, access grants, ...) must be among
+    /// them.
+    ///
+    /// Claim receipts are not children here: they are replay protection and
+    /// are reclaimed through `close_claim_receipt` once they expire.
+    pub fn close_user_profile<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseUserProfile<'info>>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let authority = ctx.accounts.authority.to_account_info();
+        let profile = &mut ctx.accounts.user_profile;
+
+        let mut closed_accounts: u32 = 0;
+        let mut record_uris = Vec::new();
+        for account in ctx.remaining_accounts {
+            if let Some(uri) =
+                close_child_account(account, profile, &authority, &ctx.accounts.config, now)?
+            {
+                record_uris.push(uri);
+            }
+            closed_accounts += 1;
+        }
+        require!(profile.child_count == 0, ErrorCode::ProfileHasOpenAccounts);
+
+        emit_cpi!(ProfileDeleted {
+            authority: profile.authority,
+            profile: profile.key(),
+            storage: profile.storage,
+            arweave_hash: profile.arweave_hash.clone(),
+            record_uris,
+            closed_accounts,
+            deleted_at: now,
+        });
+        Ok(())
+    }
+
+    /// Anchor a new health record blob to the profile at the next free index.
+    pub fn add_health_record(
+        ctx: Context<AddHealthRecord>,
+        record: HealthRecordInput,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let profile = &mut ctx.accounts.user_profile;
+        let health_record = &mut ctx.accounts.health_record;
+        health_record.init(profile, record, now, ctx.bumps.health_record)?;
+
+        emit_cpi!(HealthRecordAdded {
+            profile: health_record.profile,
+            record: health_record.key(),
+            index: health_record.index,
+            metric: health_record.metric,
+            unit: health_record.unit,
+            storage_uri: health_record.storage_uri.clone(),
+            content_hash: health_record.content_hash,
+            supersedes: None,
+        });
+        Ok(())
+    }
+
+    /// Anchor a corrected version of an existing record. The old record is
+    /// kept, pointing at its replacement through `superseded_by`.
+    pub fn supersede_health_record(
+        ctx: Context<SupersedeHealthRecord>,
+        record: HealthRecordInput,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let previous = &mut ctx.accounts.previous_record;
+        require!(
+            previous.superseded_by.is_none(),
+            ErrorCode::RecordAlreadySuperseded
+        );
+
+        let profile = &mut ctx.accounts.user_profile;
+        previous.superseded_by = Some(profile.record_count);
+        let health_record = &mut ctx.accounts.health_record;
+        health_record.init(profile, record, now, ctx.bumps.health_record)?;
+
+        emit_cpi!(HealthRecordAdded {
+            profile: health_record.profile,
+            record: health_record.key(),
+            index: health_record.index,
+            metric: health_record.metric,
+            unit: health_record.unit,
+            storage_uri: health_record.storage_uri.clone(),
+            content_hash: health_record.content_hash,
+            supersedes: Some(previous.index),
+        });
+        Ok(())
+    }
+
+    pub fn create_goal(ctx: Context<CreateGoal>, input: GoalInput) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        input.metric.check_unit(input.unit)?;
+        input.validate(now)?;
+
+        let profile = &mut ctx.accounts.user_profile;
+        let goal = &mut ctx.accounts.goal;
+        goal.profile = profile.key();
+        goal.id = profile.goal_count;
+        goal.metric = input.metric;
+        goal.unit = input.unit;
+        goal.target = input.target;
+        goal.comparator = input.comparator;
+        goal.cadence = input.cadence;
+        goal.start_ts = input.start_ts;
+        goal.end_ts = input.end_ts;
+        goal.status = GoalStatus::Active;
+        goal.created_at = now;
+        goal.bump = ctx.bumps.goal;
+
+        profile.goal_count = profile
+            .goal_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        profile.add_child()?;
 
-        msg!("Vault PDA: {}", ctx.accounts.vault_authority.key());"init_if_needed"
+        emit_cpi!(GoalCreated {
+            profile: goal.profile,
+            goal: goal.key(),
+            id: goal.id,
+            metric: goal.metric,
+            unit: goal.unit,
+            target: goal.target,
+            comparator: goal.comparator,
+            cadence: goal.cadence,
+            start_ts: goal.start_ts,
+            end_ts: goal.end_ts,
+        });
+        Ok(())
+    }
 
-        // PDA signer seeds for the vault authority
-        let bump = ctx.bumps.vault_authority;
-        let seeds: &[&[u8]] = &[b"vault", &[bump]];
-        let signer: &[&[&[u8]]] = &[seeds];
+    /// Change a goal's terms. Only allowed before the goal window opens, so
+    /// the target cannot be moved once progress is being measured.
+    pub fn update_goal(ctx: Context<UpdateGoal>, update: GoalUpdate) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let goal = &mut ctx.accounts.goal;
+        require!(goal.status == GoalStatus::Active, ErrorCode::GoalNotActive);
+        require!(now < goal.start_ts, ErrorCode::GoalAlreadyStarted);
 
-        // Transfer from vault -> user ATA
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.vault_token_account.to_account_info(),
-            to: ctx.accounts.user_token_account.to_account_info(),
-            authority: ctx.accounts.vault_authority.to_account_info(),
+        let input = GoalInput {
+            metric: goal.metric,
+            unit: goal.unit,
+            target: update.target.unwrap_or(goal.target),
+            comparator: update.comparator.unwrap_or(goal.comparator),
+            cadence: update.cadence.unwrap_or(goal.cadence),
+            start_ts: update.start_ts.unwrap_or(goal.start_ts),
+            end_ts: update.end_ts.unwrap_or(goal.end_ts),
         };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
+        input.validate(now)?;
+
+        goal.target = input.target;
+        goal.comparator = input.comparator;
+        goal.cadence = input.cadence;
+        goal.start_ts = input.start_ts;
+        goal.end_ts = input.end_ts;
+
+        emit_cpi!(GoalUpdated {
+            goal: goal.key(),
+            target: goal.target,
+            comparator: goal.comparator,
+            cadence: goal.cadence,
+            start_ts: goal.start_ts,
+            end_ts: goal.end_ts,
+        });
+        Ok(())
+    }
+
+    pub fn abandon_goal(ctx: Context<UpdateGoal>) -> Result<()> {
+        let goal = &mut ctx.accounts.goal;
+        require!(goal.status == GoalStatus::Active, ErrorCode::GoalNotActive);
+        goal.status = GoalStatus::Abandoned;
+
+        emit_cpi!(GoalStatusChanged {
+            profile: goal.profile,
+            goal: goal.key(),
+            status: goal.status,
+        });
+        Ok(())
+    }
+
+    /// Settle a goal whose window has closed against an oracle attestation of
+    /// how many cadence periods met the target. A goal that met every period
+    /// is marked completed and pays `ProtocolConfig::goal_reward`; otherwise
+    /// it is marked failed. Either way it can only be settled once.
+    pub fn settle_goal(ctx: Context<SettleGoal>, attestation: GoalAttestation) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let goal = &mut ctx.accounts.goal;
+        require!(
+            attestation.matches(goal, &ctx.accounts.authority.key()),
+            ErrorCode::AttestationMismatch
+        );
+        let oracle = verify_oracle_attestation(
+            &ctx.accounts.config,
+            &ctx.accounts.instructions_sysvar,
+            &attestation.message()?,
+        )?;
 
-        token::transfer(
-            CpiContext::new_with_signer(cpi_program, cpi_accounts, signer),
+        goal.settle(attestation.periods_met, now)?;
+        emit_cpi!(GoalStatusChanged {
+            profile: goal.profile,
+            goal: goal.key(),
+            status: goal.status,
+        });
+
+        let amount = ctx.accounts.config.goal_reward;
+        if goal.status == GoalStatus::Failed || amount == 0 {
+            return Ok(());
+        }
+        let ledger = &mut ctx.accounts.reward_ledger;
+        ledger.init_if_empty(ctx.accounts.authority.key(), ctx.bumps.reward_ledger);
+        ctx.accounts
+            .config
+            .record_emission(ledger, amount, RewardReason::GoalCompletion, now)?;
+
+        transfer_from_vault(
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.token_program,
+            ctx.bumps.vault_authority,
             amount,
         )?;
 
+        emit_cpi!(RewardPaid {
+            recipient: ctx.accounts.authority.key(),
+            amount,
+            reason: RewardReason::GoalCompletion,
+            issuer: oracle,
+            pool: None,
+        });
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct InitializeUserProfile<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + 32 + 4 + 100 + 4 + 100 + 8,
-        seeds = [b"user_profile", authority.key().as_ref()],
-        bump
-    )]
-    pub user_profile: Account<'info, UserProfile>,
+    /// Reclaim the rent of a goal that is settled, abandoned, or past its
+    /// window without having been settled.
+    pub fn close_goal(ctx: Context<CloseGoal>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let goal = &ctx.accounts.goal;
+        require!(
+            goal.status != GoalStatus::Active || now > goal.end_ts,
+            ErrorCode::GoalStillActive
+        );
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        let profile = &mut ctx.accounts.user_profile;
+        profile.remove_child()?;
 
-    pub system_program: Program<'info, System>,
-}
+        emit_cpi!(GoalClosed {
+            profile: profile.key(),
+            goal: goal.key(),
+        });
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct RewardUser<'info> {
-    // 1) PDA authority first
-    #[account(
-        seeds = [b"vault"],
-        bump,
-    )]
-    /// CHECK: PDA signer — verified by seeds
-    pub vault_authority: UncheckedAccount<'info>,
+    /// Record the user's consent for `grantee` to access the records in
+    /// `scope` for `purpose` until `expires_at`. `mode` can further limit the
+    /// grant to a number of reads or a single export.
+    pub fn grant_access(
+        ctx: Context<GrantAccess>,
+        grantee: Pubkey,
+        scope: GrantScope,
+        expires_at: i64,
+        purpose: PurposeCode,
+        mode: GrantMode,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        scope.validate()?;
+        mode.validate()?;
+        require!(expires_at > now, ErrorCode::InvalidGrantExpiry);
 
-    // 2) Mint before any ATAs that reference it
-    pub mint: Account<'info, Mint>,
+        let profile = &mut ctx.accounts.user_profile;
+        let grant = &mut ctx.accounts.access_grant;
+        grant.profile = profile.key();
+        grant.grantee = grantee;
+        grant.scope = scope;
+        grant.expires_at = expires_at;
+        grant.purpose = purpose;
+        grant.mode = mode;
+        grant.created_at = now;
+        grant.bump = ctx.bumps.access_grant;
 
-    // 3) Payer/signers before accounts that reference them
-    #[account(mut)]
-    pub user: Signer<'info>,
+        profile.add_child()?;
 
-    // 4) User ATA can reference `user` and `mint` (both are above now)
-    #[account(
-        init_if_needed,
-        payer = user,
-        associated_token::mint = mint,
-        associated_token::authority = user,
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
+        emit_cpi!(AccessGranted {
+            profile: grant.profile,
+            grantee: grant.grantee,
+            grant: grant.key(),
+            scope: grant.scope.clone(),
+            expires_at: grant.expires_at,
+            purpose: grant.purpose,
+            mode: grant.mode,
+        });
+        Ok(())
+    }
 
-    // 5) Vault ATA can reference `vault_authority` and `mint` (both are above)
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = vault_authority,
-    )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    /// Withdraw consent by closing the grant.
+    pub fn revoke_access(ctx: Context<RevokeAccess>) -> Result<()> {
+        let profile = &mut ctx.accounts.user_profile;
+        profile.remove_child()?;
 
-    // Programs needed for ATA creation / CPI
-    pub system_program: Program<'info, System>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
-}
+        let grant = &ctx.accounts.access_grant;
+        emit_cpi!(AccessRevoked {
+            profile: grant.profile,
+            grantee: grant.grantee,
+            grant: grant.key(),
+            expired: false,
+        });
+        Ok(())
+    }
 
-#[account]
-pub struct UserProfile {
-    pub authority: Pubkey,
-    pub arweave_hash: String,
-    pub goal: String,
-    pub created_at: i64,
-}
+    /// Create the profile's access log ahead of time. Otherwise the first
+    /// `record_access` creates it at the grantee's expense.
+    pub fn initialize_access_log(ctx: Context<InitializeAccessLog>) -> Result<()> {
+        let mut log = ctx.accounts.access_log.load_init()?;
+        log.profile = ctx.accounts.user_profile.key();
+        ctx.accounts.user_profile.add_child()?;
 
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Amount must be greater than zero")]
-    InvalidAmount,
+        emit_cpi!(AccessLogCreated {
+            profile: log.profile,
+            access_log: ctx.accounts.access_log.key(),
+        });
+        Ok(())
+    }
+
+    /// Called by a grantee each time it accesses a record. Consumes one use
+    /// of a read-limited or one-time-export grant, appends an entry to the
+    /// profile's access log (creating it on first use, paid by the grantee)
+    /// and emits `RecordAccessed`.
+    pub fn record_access(ctx: Context<RecordAccess>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let record = &ctx.accounts.health_record;
+        let grant = &mut ctx.accounts.access_grant;
+        grant.check_usable(now)?;
+        require!(grant.covers(record), ErrorCode::RecordNotInGrantScope);
+        grant.mode.consume();
+
+        // `init_if_needed` leaves the discriminator unset until the
+        // instruction exits, so only a log created just now accepts
+        // `load_init`.
+        let access_log = &ctx.accounts.access_log;
+        let mut log = match access_log.load_init() {
+            Ok(mut log) => {
+                log.profile = ctx.accounts.user_profile.key();
+                ctx.accounts.user_profile.add_child()?;
+                emit_cpi!(AccessLogCreated {
+                    profile: log.profile,
+                    access_log: access_log.key(),
+                });
+                log
+            }
+            Err(_) => access_log.load_mut()?,
+        };
+        log.push(AccessLogEntry {
+            grantee: grant.grantee,
+            grant: grant.key(),
+            accessed_at: now,
+            record_index: record.index,
+            metric: record.metric as u8,
+            purpose: grant.purpose as u8,
+            _padding: [0; 6],
+        });
+
+        emit_cpi!(RecordAccessed {
+            profile: grant.profile,
+            grantee: grant.grantee,
+            grant: grant.key(),
+            record: record.key(),
+            record_index: record.index,
+            metric: record.metric,
+            purpose: grant.purpose,
+            accessed_at: now,
+        });
+        Ok(())
+    }
+
+    /// Close a grant that has expired or has no uses left. Callable by
+    /// anyone; rent goes back to the profile owner.
+    pub fn close_expired_grant(ctx: Context<CloseExpiredGrant>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts.access_grant.check_usable(now).is_err(),
+            ErrorCode::GrantStillActive
+        );
+        ctx.accounts.user_profile.remove_child()?;
+
+        let grant = &ctx.accounts.access_grant;
+        emit_cpi!(AccessRevoked {
+            profile: grant.profile,
+            grantee: grant.grantee,
+            grant: grant.key(),
+            expired: true,
+        });
+        Ok(())
+    }
+
+    /// Store the record's symmetric key wrapped for a grantee: an X25519
+    /// ephemeral public key plus the AEAD ciphertext of the key under the
+    /// shared secret with the grantee. Requires a live grant covering the
+    /// record. Re-sharing overwrites the grantee's previous envelope.
+    pub fn share_record_key(
+        ctx: Context<ShareRecordKey>,
+        ephemeral_pubkey: [u8; 32],
+        wrapped_key: Vec<u8>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !wrapped_key.is_empty() && wrapped_key.len() <= MAX_WRAPPED_KEY_LEN,
+            ErrorCode::InvalidWrappedKey
+        );
+        let record = &ctx.accounts.health_record;
+        let grant = &ctx.accounts.access_grant;
+        grant.check_usable(now)?;
+        require!(grant.covers(record), ErrorCode::RecordNotInGrantScope);
+
+        let envelope = &mut ctx.accounts.key_envelope;
+        if envelope.record == Pubkey::default() {
+            ctx.accounts.user_profile.add_child()?;
+        }
+        envelope.profile = ctx.accounts.user_profile.key();
+        envelope.record = record.key();
+        envelope.grantee = grant.grantee;
+        envelope.key_version = record.key_version;
+        envelope.ephemeral_pubkey = ephemeral_pubkey;
+        envelope.wrapped_key = wrapped_key;
+        envelope.created_at = now;
+        envelope.bump = ctx.bumps.key_envelope;
+
+        emit_cpi!(RecordKeyShared {
+            record: envelope.record,
+            grantee: envelope.grantee,
+            key_version: envelope.key_version,
+        });
+        Ok(())
+    }
+
+    /// Point the record at a blob re-encrypted under a fresh key and bump its
+    /// key version. Envelopes for older versions no longer match the record,
+    /// so grantees whose access was revoked cannot decrypt the new blob.
+    pub fn rotate_record_key(
+        ctx: Context<RotateRecordKey>,
+        storage_uri: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        validate_storage_uri(&storage_uri)?;
+        let record = &mut ctx.accounts.health_record;
+        record.storage_uri = storage_uri;
+        record.content_hash = content_hash;
+        record.key_version = record
+            .key_version
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit_cpi!(RecordKeyRotated {
+            record: record.key(),
+            key_version: record.key_version,
+            storage_uri: record.storage_uri.clone(),
+            content_hash: record.content_hash,
+        });
+        Ok(())
+    }
+
+    /// Delete a key envelope, e.g. one left stale by `rotate_record_key`.
+    pub fn close_key_envelope(ctx: Context<CloseKeyEnvelope>) -> Result<()> {
+        ctx.accounts.user_profile.remove_child()?;
+
+        let envelope = &ctx.accounts.key_envelope;
+        emit_cpi!(KeyEnvelopeClosed {
+            record: envelope.record,
+            grantee: envelope.grantee,
+        });
+        Ok(())
+    }
+
+    /// Open a sponsored reward campaign with its own mint, vault, budget and
+    /// issuers. The pool's vault ATA is owned by the pool PDA.
+    pub fn create_reward_pool(
+        ctx: Context<CreateRewardPool>,
+        id: u64,
+        input: RewardPoolInput,
+    ) -> Result<()> {
+        validate_issuers(&input.issuers)?;
+        require!(input.budget > 0, ErrorCode::InvalidAmount);
+        require!(
+            input.epoch_cap > 0 && input.epoch_cap <= input.budget,
+            ErrorCode::InvalidRewardLimits
+        );
+        require!(input.start_ts < input.end_ts, ErrorCode::InvalidPoolWindow);
+
+        let pool = &mut ctx.accounts.reward_pool;
+        pool.id = id;
+        pool.mint = ctx.accounts.mint.key();
+        pool.issuers = input.issuers;
+        pool.budget = input.budget;
+        pool.distributed = 0;
+        pool.epoch_cap = input.epoch_cap;
+        pool.epoch_start = 0;
+        pool.epoch_distributed = 0;
+        pool.total_funded = 0;
+        pool.total_withdrawn = 0;
+        pool.start_ts = input.start_ts;
+        pool.end_ts = input.end_ts;
+        pool.bump = ctx.bumps.reward_pool;
+
+        emit_cpi!(RewardPoolCreated {
+            pool: pool.key(),
+            id,
+            mint: pool.mint,
+            budget: pool.budget,
+            epoch_cap: pool.epoch_cap,
+            start_ts: pool.start_ts,
+            end_ts: pool.end_ts,
+        });
+        Ok(())
+    }
+
+    /// Replace the set of keys allowed to pay out of a pool. A key only pays
+    /// while it is also listed in the protocol's issuers.
+    pub fn set_pool_issuers(ctx: Context<UpdateRewardPool>, issuers: Vec<Pubkey>) -> Result<()> {
+        validate_issuers(&issuers)?;
+        let pool = &mut ctx.accounts.reward_pool;
+        pool.issuers = issuers.clone();
+
+        emit_cpi!(RewardPoolIssuersUpdated {
+            pool: pool.key(),
+            issuers,
+        });
+        Ok(())
+    }
+
+    /// Deposit `amount` of the pool's mint into its vault. Anyone may fund.
+    pub fn fund_reward_pool(ctx: Context<FundRewardPool>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.funder_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.pool_vault.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let pool = &mut ctx.accounts.reward_pool;
+        pool.total_funded = pool
+            .total_funded
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit_cpi!(RewardPoolFunded {
+            pool: pool.key(),
+            funder: ctx.accounts.funder.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Transfer tokens from a reward pool's vault to the user's ATA.
+    /// Automatically creates the user's ATA if it doesn't exist.
+    /// Must be co-signed by a key that is both a protocol issuer and one of
+    /// the pool's issuers, inside the pool's window and within its budget
+    /// and epoch cap. Pools paying in the protocol mint are also bounded by
+    /// the per-user and global epoch caps. Each (pool, user, metric, period)
+    /// can only be paid once. `reason` is tallied on the user's
+    /// `RewardLedger` when the pool pays in the protocol mint. For such pools,
+    /// passing the user's stake account boosts `amount` by their staking
+    /// multiplier.
+    pub fn reward_user(
+        ctx: Context<RewardUser>,
+        amount: u64,
+        metric: MetricKind,
+        period: i64,
+        reason: RewardReason,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+        // The multiplier is a perk of staking the protocol token, so it only
+        // applies to sponsored pools paying in that same token.
+        let amount = match &ctx.accounts.stake_account {
+            Some(stake) if ctx.accounts.reward_pool.mint == ctx.accounts.config.mint => {
+                ctx.accounts.config.staking.boost(amount, stake, now)?
+            }
+            _ => amount,
+        };
+        let pool = &mut ctx.accounts.reward_pool;
+        let config = &mut ctx.accounts.config;
+        ctx.accounts.claim_receipt.record(
+            ClaimKey {
+                pool: pool.key(),
+                user: ctx.accounts.user.key(),
+                metric,
+                period,
+            },
+            amount,
+            now,
+            &config.limits,
+            ctx.bumps.claim_receipt,
+        )?;
+        pool.record_payout(amount, now, config.limits.epoch_start(now))?;
+
+        // Payouts in the protocol mint share the per-user and global epoch
+        // caps with every other reward path.
+        if pool.mint == config.mint {
+            let ledger = &mut ctx.accounts.reward_ledger;
+            ledger.init_if_empty(ctx.accounts.user.key(), ctx.bumps.reward_ledger);
+            config.record_emission(ledger, amount, reason, now)?;
+        }
+
+        transfer_from_pool(
+            pool,
+            &ctx.accounts.pool_vault,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        emit_cpi!(RewardPaid {
+            recipient: ctx.accounts.user.key(),
+            amount,
+            reason,
+            issuer: ctx.accounts.issuer.key(),
+            pool: Some(pool.key()),
+        });
+        Ok(())
+    }
+
+    /// Pay out for activity attested by a registered oracle. The transaction
+    /// must carry an Ed25519 program instruction, immediately before this one,
+    /// verifying the oracle's signature over the serialized attestation.
+    pub fn claim_attested_reward(
+        ctx: Context<ClaimAttestedReward>,
+        attestation: ActivityAttestation,
+    ) -> Result<()> {
+        require_keys_eq!(
+            attestation.user,
+            ctx.accounts.user.key(),
+            ErrorCode::AttestationUserMismatch
+        );
+
+        let oracle = verify_oracle_attestation(
+            &ctx.accounts.config,
+            &ctx.accounts.instructions_sysvar,
+            &attestation.message()?,
+        )?;
+        attestation.metric.check_unit(attestation.unit)?;
+
+        let amount = ctx
+            .accounts
+            .reward_table
+            .payout(attestation.metric, attestation.unit, attestation.value)
+            .ok_or(ErrorCode::NoRewardForActivity)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.claim_receipt.record(
+            ClaimKey {
+                pool: Pubkey::default(),
+                user: attestation.user,
+                metric: attestation.metric,
+                period: attestation.period,
+            },
+            amount,
+            now,
+            &ctx.accounts.config.limits,
+            ctx.bumps.claim_receipt,
+        )?;
+
+        let ledger = &mut ctx.accounts.reward_ledger;
+        ledger.init_if_empty(ctx.accounts.user.key(), ctx.bumps.reward_ledger);
+        ctx.accounts
+            .config
+            .record_emission(ledger, amount, RewardReason::DailyLog, now)?;
+
+        transfer_from_vault(
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.token_program,
+            ctx.bumps.vault_authority,
+            amount,
+        )?;
+
+        emit_cpi!(RewardPaid {
+            recipient: ctx.accounts.user.key(),
+            amount,
+            reason: RewardReason::DailyLog,
+            issuer: oracle,
+            pool: None,
+        });
+        Ok(())
+    }
+
+    /// Reclaim the rent of a receipt whose period is older than the claim
+    /// horizon. Claims for such periods are rejected anyway, so dropping the
+    /// receipt cannot reopen a double payment. Callable by anyone; rent goes
+    /// back to the user who paid for the receipt.
+    pub fn close_claim_receipt(ctx: Context<CloseClaimReceipt>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !ctx.accounts
+                .config
+                .limits
+                .within_claim_horizon(ctx.accounts.claim_receipt.period, now),
+            ErrorCode::ClaimReceiptStillActive
+        );
+
+        let receipt = &ctx.accounts.claim_receipt;
+        emit_cpi!(ClaimReceiptClosed {
+            user: receipt.user,
+            metric: receipt.metric,
+            period: receipt.period,
+        });
+        Ok(())
+    }
+
+    /// Lock `input.total_amount` from the vault into an escrow owned by a new
+    /// vesting schedule for `beneficiary`.
+    pub fn create_vesting(
+        ctx: Context<CreateVesting>,
+        id: u64,
+        beneficiary: Pubkey,
+        input: VestingInput,
+    ) -> Result<()> {
+        input.validate()?;
+
+        let config = &mut ctx.accounts.config;
+        config.total_distributed = config
+            .total_distributed
+            .checked_add(input.total_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let schedule = &mut ctx.accounts.vesting_schedule;
+        schedule.beneficiary = beneficiary;
+        schedule.id = id;
+        schedule.total_amount = input.total_amount;
+        schedule.released = 0;
+        schedule.start_ts = input.start_ts;
+        schedule.cliff_ts = input.cliff_ts;
+        schedule.duration = input.duration;
+        schedule.revoked_at = None;
+        schedule.bump = ctx.bumps.vesting_schedule;
+
+        transfer_from_vault(
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.vesting_vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.token_program,
+            ctx.bumps.vault_authority,
+            input.total_amount,
+        )?;
+
+        emit_cpi!(VestingCreated {
+            schedule: schedule.key(),
+            beneficiary,
+            total_amount: schedule.total_amount,
+            start_ts: schedule.start_ts,
+            cliff_ts: schedule.cliff_ts,
+            duration: schedule.duration,
+        });
+        Ok(())
+    }
+
+    /// Release everything vested so far to the beneficiary's ATA.
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let schedule = &mut ctx.accounts.vesting_schedule;
+        let amount = schedule
+            .vested_amount(now)?
+            .checked_sub(schedule.released)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+        schedule.released = schedule
+            .released
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        transfer_from_vesting(
+            schedule,
+            &ctx.accounts.vesting_vault,
+            &ctx.accounts.beneficiary_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        emit_cpi!(VestedClaimed {
+            schedule: schedule.key(),
+            beneficiary: schedule.beneficiary,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Stop vesting now and return the unvested remainder to the vault. What
+    /// has already vested stays claimable.
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let schedule = &mut ctx.accounts.vesting_schedule;
+        require!(
+            schedule.revoked_at.is_none(),
+            ErrorCode::VestingAlreadyRevoked
+        );
+        let unvested = schedule
+            .total_amount
+            .checked_sub(schedule.vested_amount(now)?)
+            .ok_or(ErrorCode::MathOverflow)?;
+        schedule.revoked_at = Some(now);
+
+        let config = &mut ctx.accounts.config;
+        config.total_distributed = config
+            .total_distributed
+            .checked_sub(unvested)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        if unvested > 0 {
+            transfer_from_vesting(
+                schedule,
+                &ctx.accounts.vesting_vault,
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                unvested,
+            )?;
+        }
+
+        emit_cpi!(VestingRevoked {
+            schedule: schedule.key(),
+            beneficiary: schedule.beneficiary,
+            returned: unvested,
+        });
+        Ok(())
+    }
+
+    /// Lock `amount` of the protocol mint in the caller's stake vault.
+    /// Adding to an existing stake moves `staked_since` forward in proportion,
+    /// so the time bonus can't be gamed by topping up an old, small stake.
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        let now = Clock::get()?.unix_timestamp;
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.owner_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.stake_vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let stake = &mut ctx.accounts.stake_account;
+        if stake.owner == Pubkey::default() {
+            stake.owner = ctx.accounts.owner.key();
+            stake.bump = ctx.bumps.stake_account;
+        }
+        let pool = &mut ctx.accounts.staking_pool;
+        pool.update(now)?;
+        stake.checkpoint(pool)?;
+        stake.add(amount, now)?;
+        pool.total_staked = pool
+            .total_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit_cpi!(Staked {
+            owner: stake.owner,
+            amount,
+            total_staked: stake.amount,
+        });
+        Ok(())
+    }
+
+    /// Move `amount` out of the active stake and start the cooldown. The
+    /// tokens stop counting towards the multiplier immediately.
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        let now = Clock::get()?.unix_timestamp;
+        let cooldown = ctx.accounts.config.staking.cooldown;
+
+        let pool = &mut ctx.accounts.staking_pool;
+        pool.update(now)?;
+        let stake = &mut ctx.accounts.stake_account;
+        stake.checkpoint(pool)?;
+        stake.amount = stake
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStake)?;
+        pool.total_staked = pool
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        stake.pending_withdrawal = stake
+            .pending_withdrawal
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        stake.unlock_ts = now.checked_add(cooldown).ok_or(ErrorCode::MathOverflow)?;
+
+        emit_cpi!(UnstakeStarted {
+            owner: stake.owner,
+            amount,
+            unlock_ts: stake.unlock_ts,
+        });
+        Ok(())
+    }
+
+    /// Return every unstaked token whose cooldown has elapsed.
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let stake = &mut ctx.accounts.stake_account;
+        let amount = stake.pending_withdrawal;
+        require!(amount > 0, ErrorCode::NothingToWithdraw);
+        require!(now >= stake.unlock_ts, ErrorCode::CooldownActive);
+        stake.pending_withdrawal = 0;
+
+        transfer_from_stake(
+            stake,
+            &ctx.accounts.stake_vault,
+            &ctx.accounts.owner_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        emit_cpi!(StakeWithdrawn {
+            owner: stake.owner,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Create the staking reward accumulator and its reward vault, streaming
+    /// `emission_rate` tokens per second to stakers pro rata for as long as
+    /// `fund_staking_rewards` deposits last.
+    pub fn initialize_staking_pool(
+        ctx: Context<InitializeStakingPool>,
+        emission_rate: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.staking_pool;
+        pool.reward_per_token_stored = 0;
+        pool.last_update_ts = Clock::get()?.unix_timestamp;
+        pool.emission_rate = emission_rate;
+        pool.total_staked = 0;
+        pool.total_funded = 0;
+        pool.total_emitted = 0;
+        pool.bump = ctx.bumps.staking_pool;
+
+        emit_cpi!(EmissionRateUpdated { emission_rate });
+        Ok(())
+    }
+
+    /// Change the emission rate. Rewards accrued so far keep the old rate.
+    pub fn set_emission_rate(ctx: Context<UpdateStakingPool>, emission_rate: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.staking_pool;
+        pool.update(Clock::get()?.unix_timestamp)?;
+        pool.emission_rate = emission_rate;
+
+        emit_cpi!(EmissionRateUpdated { emission_rate });
+        Ok(())
+    }
+
+    /// Deposit `amount` of the protocol mint into the staking reward vault.
+    /// Emissions only accrue against funded rewards, so time spent unfunded
+    /// earns nothing retroactively. Anyone may fund.
+    pub fn fund_staking_rewards(ctx: Context<FundStakingRewards>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        let pool = &mut ctx.accounts.staking_pool;
+        pool.update(Clock::get()?.unix_timestamp)?;
+        pool.total_funded = pool
+            .total_funded
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.funder_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.reward_vault.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        emit_cpi!(StakingRewardsFunded {
+            funder: ctx.accounts.funder.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Pay out the caller's accrued staking rewards from the staking reward
+    /// vault.
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.staking_pool;
+        pool.update(now)?;
+        let stake = &mut ctx.accounts.stake_account;
+        stake.checkpoint(pool)?;
+
+        let amount = stake.accrued_rewards;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+        stake.accrued_rewards = 0;
+
+        transfer_from_staking_pool(
+            pool,
+            &ctx.accounts.reward_vault,
+            &ctx.accounts.owner_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        emit_cpi!(StakingRewardsClaimed {
+            owner: ctx.accounts.owner.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Open a challenge that users join by paying `input.entry_fee` into the
+    /// challenge's vault before it starts.
+    pub fn create_challenge(
+        ctx: Context<CreateChallenge>,
+        id: u64,
+        input: ChallengeInput,
+    ) -> Result<()> {
+        input.validate(Clock::get()?.unix_timestamp)?;
+
+        let challenge = &mut ctx.accounts.challenge;
+        challenge.creator = ctx.accounts.creator.key();
+        challenge.id = id;
+        challenge.metric = input.metric;
+        challenge.unit = input.unit;
+        challenge.target = input.target;
+        challenge.start_ts = input.start_ts;
+        challenge.end_ts = input.end_ts;
+        challenge.entry_fee = input.entry_fee;
+        challenge.max_participants = input.max_participants;
+        challenge.participant_count = 0;
+        challenge.winner_count = 0;
+        challenge.pot = 0;
+        challenge.prize = 0;
+        challenge.closed_entries = 0;
+        challenge.status = ChallengeStatus::Open;
+        challenge.bump = ctx.bumps.challenge;
+
+        emit_cpi!(ChallengeCreated {
+            challenge: challenge.key(),
+            creator: challenge.creator,
+            id,
+            metric: challenge.metric,
+            unit: challenge.unit,
+            target: challenge.target,
+            start_ts: challenge.start_ts,
+            end_ts: challenge.end_ts,
+            entry_fee: challenge.entry_fee,
+            max_participants: challenge.max_participants,
+        });
+        Ok(())
+    }
+
+    /// Escrow the entry fee and register the caller as a participant.
+    pub fn join_challenge(ctx: Context<JoinChallenge>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let challenge = &mut ctx.accounts.challenge;
+        require!(now < challenge.start_ts, ErrorCode::ChallengeAlreadyStarted);
+        require!(
+            challenge.participant_count < challenge.max_participants,
+            ErrorCode::ChallengeFull
+        );
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.participant_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.challenge_vault.to_account_info(),
+                    authority: ctx.accounts.participant.to_account_info(),
+                },
+            ),
+            challenge.entry_fee,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        challenge.pot = challenge
+            .pot
+            .checked_add(challenge.entry_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+        challenge.participant_count += 1;
+
+        let entry = &mut ctx.accounts.challenge_entry;
+        entry.challenge = challenge.key();
+        entry.participant = ctx.accounts.participant.key();
+        entry.reached_target = false;
+        entry.bump = ctx.bumps.challenge_entry;
+
+        emit_cpi!(ChallengeJoined {
+            challenge: entry.challenge,
+            participant: entry.participant,
+            pot: challenge.pot,
+        });
+        Ok(())
+    }
+
+    /// Mark a participant as having reached the target, on the strength of
+    /// an oracle attestation (Ed25519 instruction immediately before this
+    /// one). Anyone may relay it once the challenge has ended.
+    pub fn record_challenge_result(
+        ctx: Context<RecordChallengeResult>,
+        attestation: ChallengeAttestation,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let challenge = &mut ctx.accounts.challenge;
+        require!(
+            challenge.status == ChallengeStatus::Open,
+            ErrorCode::ChallengeNotOpen
+        );
+        require!(now >= challenge.end_ts, ErrorCode::ChallengeNotEnded);
+        require!(
+            now < challenge.results_deadline()?,
+            ErrorCode::ChallengeResultWindowClosed
+        );
+        require!(
+            attestation.matches(challenge),
+            ErrorCode::AttestationMismatch
+        );
+        require!(
+            attestation.value >= challenge.target,
+            ErrorCode::ChallengeTargetNotReached
+        );
+        verify_oracle_attestation(
+            &ctx.accounts.config,
+            &ctx.accounts.instructions_sysvar,
+            &attestation.message()?,
+        )?;
+
+        let entry = &mut ctx.accounts.challenge_entry;
+        require!(
+            !entry.reached_target,
+            ErrorCode::ChallengeResultAlreadyRecorded
+        );
+        entry.reached_target = true;
+        challenge.winner_count += 1;
+
+        emit_cpi!(ChallengeResultRecorded {
+            challenge: challenge.key(),
+            participant: entry.participant,
+            value: attestation.value,
+        });
+        Ok(())
+    }
+
+    /// Fix each winner's share of the pot once the result window has
+    /// closed. With no winners, every participant gets their fee back.
+    /// Anyone may settle.
+    pub fn settle_challenge(ctx: Context<SettleChallenge>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let challenge = &mut ctx.accounts.challenge;
+        require!(
+            challenge.status == ChallengeStatus::Open,
+            ErrorCode::ChallengeNotOpen
+        );
+        require!(
+            now >= challenge.results_deadline()?,
+            ErrorCode::ChallengeResultWindowOpen
+        );
+
+        challenge.settle();
+
+        emit_cpi!(ChallengeSettled {
+            challenge: challenge.key(),
+            winner_count: challenge.winner_count,
+            prize: challenge.prize,
+        });
+        Ok(())
+    }
+
+    /// Pay a winner their prize (or, if nobody won, refund the entry fee)
+    /// and close their entry.
+    pub fn claim_challenge_prize(ctx: Context<ClaimChallengePrize>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let challenge = &mut ctx.accounts.challenge;
+        require!(
+            challenge.status == ChallengeStatus::Settled,
+            ErrorCode::ChallengeNotSettled
+        );
+        let won = challenge.winner_count > 0;
+        require!(
+            now < challenge.claim_deadline()?,
+            ErrorCode::ChallengeClaimWindowClosed
+        );
+        require!(
+            challenge.is_owed_payout(&ctx.accounts.challenge_entry),
+            ErrorCode::NotAChallengeWinner
+        );
+        challenge.closed_entries += 1;
+        let amount = challenge.prize;
+
+        if won {
+            let ledger = &mut ctx.accounts.reward_ledger;
+            ledger.init_if_empty(ctx.accounts.participant.key(), ctx.bumps.reward_ledger);
+            ledger.credit(RewardReason::ChallengeWin, amount, now)?;
+        }
+
+        transfer_from_challenge(
+            challenge,
+            &ctx.accounts.challenge_vault,
+            &ctx.accounts.participant_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        emit_cpi!(ChallengePrizeClaimed {
+            challenge: challenge.key(),
+            participant: ctx.accounts.participant.key(),
+            amount,
+            refund: !won,
+        });
+        Ok(())
+    }
+
+    /// Return the rent of a losing entry once the challenge is settled, or of
+    /// any entry whose payout went unclaimed past the claim deadline.
+    /// Callable by anyone; rent goes back to the participant.
+    pub fn close_challenge_entry(ctx: Context<CloseChallengeEntry>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let challenge = &mut ctx.accounts.challenge;
+        require!(
+            challenge.status == ChallengeStatus::Settled,
+            ErrorCode::ChallengeNotSettled
+        );
+        require!(
+            !challenge.is_owed_payout(&ctx.accounts.challenge_entry)
+                || now >= challenge.claim_deadline()?,
+            ErrorCode::ChallengeEntryClaimable
+        );
+        challenge.closed_entries += 1;
+
+        emit_cpi!(ChallengeEntryClosed {
+            challenge: challenge.key(),
+            participant: ctx.accounts.participant.key(),
+        });
+        Ok(())
+    }
+
+    /// Close a settled challenge once every entry is closed, or once the
+    /// claim deadline has passed, sweeping what is left in its vault
+    /// (rounding dust plus any unclaimed payouts) to the creator and
+    /// returning the rent of both accounts.
+    pub fn close_challenge(ctx: Context<CloseChallenge>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let challenge = &ctx.accounts.challenge;
+        require!(
+            challenge.status == ChallengeStatus::Settled,
+            ErrorCode::ChallengeNotSettled
+        );
+        require!(
+            challenge.closed_entries == challenge.participant_count
+                || now >= challenge.claim_deadline()?,
+            ErrorCode::ChallengeEntriesOpen
+        );
+
+        let swept = ctx.accounts.challenge_vault.amount;
+        if swept > 0 {
+            transfer_from_challenge(
+                challenge,
+                &ctx.accounts.challenge_vault,
+                &ctx.accounts.creator_token_account,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                swept,
+            )?;
+        }
+
+        let id = challenge.id.to_le_bytes();
+        let seeds: &[&[u8]] = &[
+            b"challenge",
+            challenge.creator.as_ref(),
+            &id,
+            &[challenge.bump],
+        ];
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.challenge_vault.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: challenge.to_account_info(),
+            },
+            &[seeds],
+        ))?;
+
+        emit_cpi!(ChallengeClosed {
+            challenge: challenge.key(),
+            swept,
+        });
+        Ok(())
+    }
+}
+
+/// Close a program-owned account that belongs to `authority`, sending its
+/// rent to `authority`. Fails on any account type that is not a profile child.
+/// Returns the `storage_uri` of a closed health record.
+fn close_child_account<'info>(
+    account: &'info AccountInfo<'info>,
+    profile: &mut Account<'info, UserProfile>,
+    authority: &AccountInfo<'info>,
+    config: &ProtocolConfig,
+    now: i64,
+) -> Result<Option<String>> {
+    require_keys_eq!(*account.owner, crate::ID, ErrorCode::InvalidChildAccount);
+    let discriminator = account
+        .try_borrow_data()?
+        .get(..8)
+        .map(<[u8]>::to_vec)
+        .ok_or(ErrorCode::InvalidChildAccount)?;
+
+    if discriminator == RewardLedger::DISCRIMINATOR {
+        let ledger = Account::<RewardLedger>::try_from(account)?;
+        require_keys_eq!(
+            ledger.authority,
+            authority.key(),
+            ErrorCode::ChildAccountMismatch
+        );
+        // Closing mid-epoch would reset the user's cap for the epoch.
+        require!(
+            ledger.claimed_in_window == 0 || ledger.window_start != config.limits.epoch_start(now),
+            ErrorCode::RewardLedgerWindowOpen
+        );
+        ledger.close(authority.clone())?;
+        return Ok(None);
+    }
+
+    if discriminator == HealthRecord::DISCRIMINATOR {
+        let record = close_profile_child::<HealthRecord>(account, profile, authority)?;
+        return Ok(Some(record.storage_uri));
+    }
+    if discriminator == Goal::DISCRIMINATOR {
+        close_profile_child::<Goal>(account, profile, authority)?;
+        return Ok(None);
+    }
+    if discriminator == AccessGrant::DISCRIMINATOR {
+        close_profile_child::<AccessGrant>(account, profile, authority)?;
+        return Ok(None);
+    }
+    if discriminator == KeyEnvelope::DISCRIMINATOR {
+        close_profile_child::<KeyEnvelope>(account, profile, authority)?;
+        return Ok(None);
+    }
+    if discriminator == AccessLog::DISCRIMINATOR {
+        let log = AccountLoader::<AccessLog>::try_from(account)?;
+        require_keys_eq!(
+            log.load()?.profile,
+            profile.key(),
+            ErrorCode::ChildAccountMismatch
+        );
+        profile.remove_child()?;
+        log.close(authority.clone())?;
+        return Ok(None);
+    }
+
+    err!(ErrorCode::InvalidChildAccount)
+}
+
+/// Close a child account of `profile` and return its last contents.
+fn close_profile_child<'info, T>(
+    account: &'info AccountInfo<'info>,
+    profile: &mut Account<'info, UserProfile>,
+    authority: &AccountInfo<'info>,
+) -> Result<T>
+where
+    T: ProfileChild + AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    let child = Account::<T>::try_from(account)?;
+    require_keys_eq!(
+        child.profile(),
+        profile.key(),
+        ErrorCode::ChildAccountMismatch
+    );
+    profile.remove_child()?;
+    child.close(authority.clone())?;
+    Ok(child.into_inner())
+}
+
+/// Transfer `amount` from the vault ATA, signing as the `vault` PDA.
+fn transfer_from_vault<'info>(
+    vault_token_account: &InterfaceAccount<'info, TokenAccount>,
+    user_token_account: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault_authority: &UncheckedAccount<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    bump: u8,
+    amount: u64,
+) -> Result<()> {
+    // PDA signer seeds for the vault authority
+    let seeds: &[&[u8]] = &[b"vault", &[bump]];
+    let signer: &[&[&[u8]]] = &[seeds];
+
+    // Transfer from vault -> user ATA. `transfer_checked` works for both the
+    // Token and Token-2022 programs.
+    let cpi_accounts = TransferChecked {
+        from: vault_token_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: user_token_account.to_account_info(),
+        authority: vault_authority.to_account_info(),
+    };
+    let cpi_program = token_program.to_account_info();
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer),
+        amount,
+        mint.decimals,
+    )
+}
+
+fn transfer_from_pool<'info>(
+    pool: &Account<'info, RewardPool>,
+    pool_vault: &InterfaceAccount<'info, TokenAccount>,
+    user_token_account: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let id = pool.id.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"reward_pool", &id, &[pool.bump]];
+    let signer: &[&[&[u8]]] = &[seeds];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: pool_vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: user_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+fn transfer_from_vesting<'info>(
+    schedule: &Account<'info, VestingSchedule>,
+    vesting_vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let id = schedule.id.to_le_bytes();
+    let seeds: &[&[u8]] = &[
+        b"vesting",
+        schedule.beneficiary.as_ref(),
+        &id,
+        &[schedule.bump],
+    ];
+    let signer: &[&[&[u8]]] = &[seeds];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vesting_vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: schedule.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+fn transfer_from_stake<'info>(
+    stake: &Account<'info, StakeAccount>,
+    stake_vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"stake", stake.owner.as_ref(), &[stake.bump]];
+    let signer: &[&[&[u8]]] = &[seeds];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: stake_vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: stake.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+fn transfer_from_staking_pool<'info>(
+    pool: &Account<'info, StakingPool>,
+    reward_vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"staking_pool", &[pool.bump]];
+    let signer: &[&[&[u8]]] = &[seeds];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: reward_vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+fn transfer_from_challenge<'info>(
+    challenge: &Account<'info, Challenge>,
+    challenge_vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let id = challenge.id.to_le_bytes();
+    let seeds: &[&[u8]] = &[
+        b"challenge",
+        challenge.creator.as_ref(),
+        &id,
+        &[challenge.bump],
+    ];
+    let signer: &[&[&[u8]]] = &[seeds];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: challenge_vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: challenge.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Verify `message` was signed by one of the oracles registered in `config`,
+/// returning the oracle's key.
+fn verify_oracle_attestation(
+    config: &ProtocolConfig,
+    instructions: &AccountInfo,
+    message: &[u8],
+) -> Result<Pubkey> {
+    let oracle = verify_ed25519_attestation(instructions, message)?;
+    require!(config.is_oracle(&oracle), ErrorCode::UnknownOracle);
+    Ok(oracle)
+}
+
+/// Size of the header and of one `Ed25519SignatureOffsets` entry in an
+/// Ed25519 program instruction.
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Check that the instruction preceding the current one is an Ed25519 program
+/// instruction carrying exactly one signature over `message`, with all data
+/// inline. Returns the public key that signed it.
+fn verify_ed25519_attestation(instructions: &AccountInfo, message: &[u8]) -> Result<Pubkey> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, ErrorCode::MissingEd25519Instruction);
+    let ix = load_instruction_at_checked((current - 1) as usize, instructions)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        ErrorCode::MissingEd25519Instruction
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidEd25519Instruction
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = ED25519_HEADER_LEN;
+    let signature_ix = read_u16(offsets + 2);
+    let pubkey_offset = read_u16(offsets + 4) as usize;
+    let pubkey_ix = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_len = read_u16(offsets + 10) as usize;
+    let message_ix = read_u16(offsets + 12);
+
+    // Offsets pointing at other instructions would let the signature cover
+    // bytes we are not looking at here.
+    require!(
+        signature_ix == u16::MAX && pubkey_ix == u16::MAX && message_ix == u16::MAX,
+        ErrorCode::InvalidEd25519Instruction
+    );
+
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + 32)
+        .ok_or(ErrorCode::InvalidEd25519Instruction)?;
+    let signed = data
+        .get(message_offset..message_offset + message_len)
+        .ok_or(ErrorCode::InvalidEd25519Instruction)?;
+    require!(signed == message, ErrorCode::AttestationMismatch);
+
+    Pubkey::try_from(pubkey).map_err(|_| error!(ErrorCode::InvalidEd25519Instruction))
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeUserProfile<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateUserProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeProtocol<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // Only the program's upgrade authority may claim the config, so nobody
+    // can front-run the deployer and install themselves as admin.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = ProgramData::owner(),
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ ErrorCode::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRewardTable<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + RewardTable::INIT_SPACE,
+        seeds = [b"reward_table"],
+        bump
+    )]
+    pub reward_table: Account<'info, RewardTable>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub guardian: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = mint @ ErrorCode::InvalidMint,
+        has_one = treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"vault"], bump)]
+    /// CHECK: PDA signer — verified by seeds
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: only used as the ATA owner, pinned by `config.treasury`
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EmergencyWithdrawPool<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool", reward_pool.id.to_le_bytes().as_ref()],
+        bump = reward_pool.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: only used as the ATA owner, pinned by `config.treasury`
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"vault"], bump)]
+    /// CHECK: PDA signer — verified by seeds
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = funder,
+        token::token_program = token_program,
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateRewardPool<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RewardPool::INIT_SPACE,
+        seeds = [b"reward_pool", id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // The pool PDA is known before creation, so anyone can open its ATA
+    // first; `init` would then fail and block this pool id for good.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRewardPool<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool", reward_pool.id.to_le_bytes().as_ref()],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundRewardPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_pool", reward_pool.id.to_le_bytes().as_ref()],
+        bump = reward_pool.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = funder,
+        token::token_program = token_program,
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(amount: u64, metric: MetricKind, period: i64)]
+pub struct RewardUser<'info> {
+    // 0) Protocol config carries the pause switch, the claim horizon and the
+    //    emission caps
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // 1) The pool pins the mint and the issuer set, and owns the vault
+    #[account(
+        mut,
+        seeds = [b"reward_pool", reward_pool.id.to_le_bytes().as_ref()],
+        bump = reward_pool.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    // 2) Mint before any ATAs that reference it
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // 3) Payer/signers before accounts that reference them
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Pool issuers must also be protocol issuers, so removing a key from the
+    // config revokes it across every pool at once.
+    #[account(
+        constraint = config.is_issuer(&issuer.key()) @ ErrorCode::UnauthorizedIssuer,
+        constraint = reward_pool.is_issuer(&issuer.key()) @ ErrorCode::UnauthorizedIssuer,
+    )]
+    pub issuer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + RewardLedger::INIT_SPACE,
+        seeds = [b"reward_ledger", user.key().as_ref()],
+        bump
+    )]
+    pub reward_ledger: Account<'info, RewardLedger>,
+
+    #[account(
+        seeds = [b"stake", user.key().as_ref()],
+        bump = stake_account.bump,
+    )]
+    pub stake_account: Option<Account<'info, StakeAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + ClaimReceipt::INIT_SPACE,
+        seeds = [
+            b"claim_receipt",
+            reward_pool.key().as_ref(),
+            user.key().as_ref(),
+            &[metric as u8],
+            &period.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+
+    // 4) User ATA can reference `user` and `mint` (both are above now)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // 5) Pool vault ATA can reference `reward_pool` and `mint` (both are above)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    // Programs needed for ATA creation / CPI
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(attestation: ActivityAttestation)]
+pub struct ClaimAttestedReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"reward_table"], bump = reward_table.bump)]
+    pub reward_table: Account<'info, RewardTable>,
+
+    #[account(
+        seeds = [b"vault"],
+        bump,
+    )]
+    /// CHECK: PDA signer — verified by seeds
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + RewardLedger::INIT_SPACE,
+        seeds = [b"reward_ledger", user.key().as_ref()],
+        bump
+    )]
+    pub reward_ledger: Account<'info, RewardLedger>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + ClaimReceipt::INIT_SPACE,
+        seeds = [
+            b"claim_receipt",
+            Pubkey::default().as_ref(),
+            user.key().as_ref(),
+            &[attestation.metric as u8],
+            &attestation.period.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = instructions_sysvar::ID)]
+    /// CHECK: instructions sysvar — verified by address
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseUserProfile<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddHealthRecord<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HealthRecord::INIT_SPACE,
+        seeds = [
+            b"health_record",
+            user_profile.key().as_ref(),
+            &user_profile.record_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub health_record: Account<'info, HealthRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SupersedeHealthRecord<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"health_record",
+            user_profile.key().as_ref(),
+            &previous_record.index.to_le_bytes(),
+        ],
+        bump = previous_record.bump,
+    )]
+    pub previous_record: Account<'info, HealthRecord>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HealthRecord::INIT_SPACE,
+        seeds = [
+            b"health_record",
+            user_profile.key().as_ref(),
+            &user_profile.record_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub health_record: Account<'info, HealthRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateGoal<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Goal::INIT_SPACE,
+        seeds = [
+            b"goal",
+            user_profile.key().as_ref(),
+            &user_profile.goal_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub goal: Account<'info, Goal>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateGoal<'info> {
+    #[account(
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"goal", user_profile.key().as_ref(), &goal.id.to_le_bytes()],
+        bump = goal.bump,
+    )]
+    pub goal: Account<'info, Goal>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleGoal<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"goal", user_profile.key().as_ref(), &goal.id.to_le_bytes()],
+        bump = goal.bump,
+    )]
+    pub goal: Account<'info, Goal>,
+
+    #[account(
+        seeds = [b"vault"],
+        bump,
+    )]
+    /// CHECK: PDA signer — verified by seeds
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RewardLedger::INIT_SPACE,
+        seeds = [b"reward_ledger", authority.key().as_ref()],
+        bump
+    )]
+    pub reward_ledger: Account<'info, RewardLedger>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = instructions_sysvar::ID)]
+    /// CHECK: instructions sysvar — verified by address
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseGoal<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"goal", user_profile.key().as_ref(), &goal.id.to_le_bytes()],
+        bump = goal.bump,
+    )]
+    pub goal: Account<'info, Goal>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(grantee: Pubkey)]
+pub struct GrantAccess<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AccessGrant::INIT_SPACE,
+        seeds = [b"access_grant", user_profile.key().as_ref(), grantee.as_ref()],
+        bump
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeAccess<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"access_grant",
+            user_profile.key().as_ref(),
+            access_grant.grantee.as_ref(),
+        ],
+        bump = access_grant.bump,
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeAccessLog<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<AccessLog>(),
+        seeds = [b"access_log", user_profile.key().as_ref()],
+        bump
+    )]
+    pub access_log: AccountLoader<'info, AccessLog>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RecordAccess<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", user_profile.authority.as_ref()],
+        bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"access_grant",
+            user_profile.key().as_ref(),
+            grantee.key().as_ref(),
+        ],
+        bump = access_grant.bump,
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+
+    #[account(
+        seeds = [
+            b"health_record",
+            user_profile.key().as_ref(),
+            &health_record.index.to_le_bytes(),
+        ],
+        bump = health_record.bump,
+    )]
+    pub health_record: Account<'info, HealthRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = grantee,
+        space = 8 + std::mem::size_of::<AccessLog>(),
+        seeds = [b"access_log", user_profile.key().as_ref()],
+        bump
+    )]
+    pub access_log: AccountLoader<'info, AccessLog>,
+
+    #[account(mut)]
+    pub grantee: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseExpiredGrant<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", owner.key().as_ref()],
+        bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            b"access_grant",
+            user_profile.key().as_ref(),
+            access_grant.grantee.as_ref(),
+        ],
+        bump = access_grant.bump,
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+
+    /// CHECK: rent recipient — the profile's authority, verified by the profile seeds
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ShareRecordKey<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        seeds = [
+            b"health_record",
+            user_profile.key().as_ref(),
+            &health_record.index.to_le_bytes(),
+        ],
+        bump = health_record.bump,
+    )]
+    pub health_record: Account<'info, HealthRecord>,
+
+    #[account(
+        seeds = [
+            b"access_grant",
+            user_profile.key().as_ref(),
+            access_grant.grantee.as_ref(),
+        ],
+        bump = access_grant.bump,
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + KeyEnvelope::INIT_SPACE,
+        seeds = [
+            b"key_envelope",
+            health_record.key().as_ref(),
+            access_grant.grantee.as_ref(),
+        ],
+        bump
+    )]
+    pub key_envelope: Account<'info, KeyEnvelope>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RotateRecordKey<'info> {
+    #[account(
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"health_record",
+            user_profile.key().as_ref(),
+            &health_record.index.to_le_bytes(),
+        ],
+        bump = health_record.bump,
+    )]
+    pub health_record: Account<'info, HealthRecord>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseKeyEnvelope<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_profile", authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        close = authority,
+        constraint = key_envelope.profile == user_profile.key() @ ErrorCode::ChildAccountMismatch,
+    )]
+    pub key_envelope: Account<'info, KeyEnvelope>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseClaimReceipt<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [
+            b"claim_receipt",
+            claim_receipt.pool.as_ref(),
+            user.key().as_ref(),
+            &[claim_receipt.metric as u8],
+            &claim_receipt.period.to_le_bytes(),
+        ],
+        bump = claim_receipt.bump,
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+
+    /// CHECK: rent recipient — must match `claim_receipt.user`
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(id: u64, beneficiary: Pubkey)]
+pub struct CreateVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = mint @ ErrorCode::InvalidMint,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"vault"], bump)]
+    /// CHECK: PDA signer — verified by seeds
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [b"vesting", beneficiary.as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // Another account may already have created this ATA; see `CreateRewardPool`.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            beneficiary.key().as_ref(),
+            vesting_schedule.id.to_le_bytes().as_ref(),
+        ],
+        bump = vesting_schedule.bump,
+        has_one = beneficiary,
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"vault"], bump)]
+    /// CHECK: PDA signer — verified by seeds
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            vesting_schedule.beneficiary.as_ref(),
+            vesting_schedule.id.to_le_bytes().as_ref(),
+        ],
+        bump = vesting_schedule.bump,
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut, seeds = [b"staking_pool"], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + StakeAccount::INIT_SPACE,
+        seeds = [b"stake", owner.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = stake_account,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut, seeds = [b"staking_pool"], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", owner.key().as_ref()],
+        bump = stake_account.bump,
+        has_one = owner,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", owner.key().as_ref()],
+        bump = stake_account.bump,
+        has_one = owner,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = stake_account,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeStakingPool<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + StakingPool::INIT_SPACE,
+        seeds = [b"staking_pool"],
+        bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // The staking pool PDA is a singleton, so a pre-created ATA must not be
+    // able to block setup.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundStakingRewards<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut, seeds = [b"staking_pool"], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = funder,
+        token::token_program = token_program,
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateStakingPool<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut, seeds = [b"staking_pool"], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimStakingRewards<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut, seeds = [b"staking_pool"], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", owner.key().as_ref()],
+        bump = stake_account.bump,
+        has_one = owner,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateChallenge<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Challenge::INIT_SPACE,
+        seeds = [b"challenge", creator.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Another account may already have created this ATA; see `CreateRewardPool`.
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program,
+    )]
+    pub challenge_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct JoinChallenge<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"challenge",
+            challenge.creator.as_ref(),
+            challenge.id.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        init,
+        payer = participant,
+        space = 8 + ChallengeEntry::INIT_SPACE,
+        seeds = [
+            b"challenge_entry",
+            challenge.key().as_ref(),
+            participant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub challenge_entry: Account<'info, ChallengeEntry>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program,
+    )]
+    pub challenge_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = participant,
+        token::token_program = token_program,
+    )]
+    pub participant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(attestation: ChallengeAttestation)]
+pub struct RecordChallengeResult<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"challenge",
+            challenge.creator.as_ref(),
+            challenge.id.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"challenge_entry",
+            challenge.key().as_ref(),
+            attestation.participant.as_ref(),
+        ],
+        bump = challenge_entry.bump,
+    )]
+    pub challenge_entry: Account<'info, ChallengeEntry>,
+
+    #[account(address = instructions_sysvar::ID)]
+    /// CHECK: instructions sysvar — verified by address
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleChallenge<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"challenge",
+            challenge.creator.as_ref(),
+            challenge.id.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimChallengePrize<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"challenge",
+            challenge.creator.as_ref(),
+            challenge.id.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        close = participant,
+        seeds = [
+            b"challenge_entry",
+            challenge.key().as_ref(),
+            participant.key().as_ref(),
+        ],
+        bump = challenge_entry.bump,
+    )]
+    pub challenge_entry: Account<'info, ChallengeEntry>,
+
+    #[account(
+        init_if_needed,
+        payer = participant,
+        space = 8 + RewardLedger::INIT_SPACE,
+        seeds = [b"reward_ledger", participant.key().as_ref()],
+        bump
+    )]
+    pub reward_ledger: Account<'info, RewardLedger>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program,
+    )]
+    pub challenge_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = participant,
+        associated_token::mint = mint,
+        associated_token::authority = participant,
+        associated_token::token_program = token_program,
+    )]
+    pub participant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseChallengeEntry<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"challenge",
+            challenge.creator.as_ref(),
+            challenge.id.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        close = participant,
+        seeds = [
+            b"challenge_entry",
+            challenge.key().as_ref(),
+            participant.key().as_ref(),
+        ],
+        bump = challenge_entry.bump,
+        has_one = participant,
+    )]
+    pub challenge_entry: Account<'info, ChallengeEntry>,
+
+    /// CHECK: rent recipient, pinned by `challenge_entry.participant`
+    #[account(mut)]
+    pub participant: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseChallenge<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = mint @ ErrorCode::InvalidMint,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            b"challenge",
+            challenge.creator.as_ref(),
+            challenge.id.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program,
+    )]
+    pub challenge_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    #[max_len(MAX_REWARD_ISSUERS)]
+    pub issuers: Vec<Pubkey>,
+    #[max_len(MAX_ORACLES)]
+    pub oracles: Vec<Pubkey>,
+    pub limits: RewardLimits,
+    /// Start of the epoch `epoch_distributed` refers to.
+    pub epoch_start: i64,
+    /// Total paid out by the vault during the current epoch.
+    pub epoch_distributed: u64,
+    /// Paid by `settle_goal` for each completed goal.
+    pub goal_reward: u64,
+    /// May flip `paused`; defaults to the admin.
+    pub guardian: Pubkey,
+    /// Owner of the token account `emergency_withdraw` pays into.
+    pub treasury: Pubkey,
+    /// While set, nothing can be paid out of the vault except through
+    /// `emergency_withdraw`.
+    pub paused: bool,
+    /// Lifetime deposits made through `fund_vault`.
+    pub total_funded: u64,
+    /// Lifetime payouts to users in the protocol mint, across all reward
+    /// paths including reward pools. Vesting grants count when locked, less
+    /// whatever a revocation returns. Staking rewards are funded separately
+    /// and tracked on `StakingPool`.
+    pub total_distributed: u64,
+    /// Lifetime `emergency_withdraw` outflows.
+    pub total_withdrawn: u64,
+    pub staking: StakingParams,
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub fn is_issuer(&self, key: &Pubkey) -> bool {
+        self.issuers.contains(key)
+    }
+
+    pub fn is_oracle(&self, key: &Pubkey) -> bool {
+        self.oracles.contains(key)
+    }
+
+    /// Charge `amount` against both the user's and the global budget for the
+    /// epoch containing `now`, rolling either window over if it has expired.
+    pub fn record_emission(
+        &mut self,
+        ledger: &mut RewardLedger,
+        amount: u64,
+        reason: RewardReason,
+        now: i64,
+    ) -> Result<()> {
+        let epoch_start = self.limits.epoch_start(now);
+
+        if ledger.window_start != epoch_start {
+            ledger.window_start = epoch_start;
+            ledger.claimed_in_window = 0;
+        }
+        let user_total = ledger
+            .claimed_in_window
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(
+            user_total <= self.limits.user_epoch_cap,
+            ErrorCode::UserRewardCapExceeded
+        );
+
+        if self.epoch_start != epoch_start {
+            self.epoch_start = epoch_start;
+            self.epoch_distributed = 0;
+        }
+        let global_total = self
+            .epoch_distributed
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(
+            global_total <= self.limits.global_epoch_cap,
+            ErrorCode::GlobalRewardCapExceeded
+        );
+
+        ledger.claimed_in_window = user_total;
+        self.epoch_distributed = global_total;
+        self.total_distributed = self
+            .total_distributed
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        ledger.credit(reason, amount, now)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct RewardLimits {
+    /// Length of a reward window in seconds (86_400 for daily caps).
+    pub epoch_duration: i64,
+    /// Maximum a single user may receive per epoch.
+    pub user_epoch_cap: u64,
+    /// Maximum the vault may pay out across all users per epoch.
+    pub global_epoch_cap: u64,
+    /// How long after a period starts it can still be claimed, in seconds.
+    /// Receipts for older periods may be garbage-collected.
+    pub claim_horizon: i64,
+}
+
+impl RewardLimits {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.epoch_duration > 0, ErrorCode::InvalidEpochDuration);
+        require!(self.claim_horizon > 0, ErrorCode::InvalidClaimHorizon);
+        require!(
+            self.user_epoch_cap <= self.global_epoch_cap,
+            ErrorCode::InvalidRewardLimits
+        );
+        Ok(())
+    }
+
+    /// Epochs are aligned to the unix epoch so every account agrees on the
+    /// window boundaries.
+    pub fn epoch_start(&self, now: i64) -> i64 {
+        now - now.rem_euclid(self.epoch_duration)
+    }
+
+    pub fn within_claim_horizon(&self, period: i64, now: i64) -> bool {
+        now.saturating_sub(period) <= self.claim_horizon
+    }
+}
+
+/// Per-user emission tracking for the current reward window, plus the
+/// user's reward history.
+#[account]
+#[derive(InitSpace)]
+pub struct RewardLedger {
+    pub authority: Pubkey,
+    pub window_start: i64,
+    pub claimed_in_window: u64,
+    /// Lifetime totals, indexed by `RewardReason as usize`.
+    pub earned_by_reason: [u64; REWARD_REASON_COUNT],
+    /// Start of the UTC calendar month `earned_this_month` covers.
+    pub month_start: i64,
+    pub earned_this_month: u64,
+    pub bump: u8,
+}
+
+impl RewardLedger {
+    /// Fill in the identity fields of a ledger that `init_if_needed` just
+    /// created.
+    pub fn init_if_empty(&mut self, authority: Pubkey, bump: u8) {
+        if self.authority == Pubkey::default() {
+            self.authority = authority;
+            self.bump = bump;
+        }
+    }
+
+    /// Add a payout to the lifetime and monthly history.
+    pub fn credit(&mut self, reason: RewardReason, amount: u64, now: i64) -> Result<()> {
+        let total = &mut self.earned_by_reason[reason as usize];
+        *total = total.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+        let month_start = utc_month_start(now);
+        if self.month_start != month_start {
+            self.month_start = month_start;
+            self.earned_this_month = 0;
+        }
+        self.earned_this_month = self
+            .earned_this_month
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// Why tokens left the vault. Only ever append variants: the discriminant
+/// indexes `RewardLedger::earned_by_reason`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RewardReason {
+    DailyLog,
+    GoalCompletion,
+    DataContribution,
+    ChallengeWin,
+    Referral,
+    ManualGrant,
+}
+
+/// Unix timestamp of midnight UTC on the first day of `ts`'s month
+/// (the day-of-month half of Howard Hinnant's `civil_from_days`).
+fn utc_month_start(ts: i64) -> i64 {
+    let days = ts.div_euclid(86_400);
+    let z = days + 719_468;
+    let doe = z - z.div_euclid(146_097) * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day_of_month = doy - (153 * mp + 2) / 5;
+    (days - day_of_month) * 86_400
+}
+
+/// A separately funded reward campaign, e.g. for one sponsor. Pays out of
+/// its own vault ATA, owned by the pool PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct RewardPool {
+    pub id: u64,
+    pub mint: Pubkey,
+    #[max_len(MAX_REWARD_ISSUERS)]
+    pub issuers: Vec<Pubkey>,
+    /// Maximum the pool will ever pay out.
+    pub budget: u64,
+    pub distributed: u64,
+    /// Maximum the pool may pay out per protocol epoch.
+    pub epoch_cap: u64,
+    /// Start of the epoch `epoch_distributed` refers to.
+    pub epoch_start: i64,
+    pub epoch_distributed: u64,
+    /// Lifetime deposits made through `fund_reward_pool`.
+    pub total_funded: u64,
+    /// Lifetime `emergency_withdraw_pool` outflows.
+    pub total_withdrawn: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub bump: u8,
+}
+
+impl RewardPool {
+    pub fn is_issuer(&self, key: &Pubkey) -> bool {
+        self.issuers.contains(key)
+    }
+
+    /// Charge `amount` against the budget and the cap for the epoch
+    /// starting at `epoch_start`, provided the pool is live.
+    pub fn record_payout(&mut self, amount: u64, now: i64, epoch_start: i64) -> Result<()> {
+        require!(
+            self.start_ts <= now && now < self.end_ts,
+            ErrorCode::PoolNotActive
+        );
+        let distributed = self
+            .distributed
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(distributed <= self.budget, ErrorCode::PoolBudgetExceeded);
+
+        if self.epoch_start != epoch_start {
+            self.epoch_start = epoch_start;
+            self.epoch_distributed = 0;
+        }
+        let epoch_total = self
+            .epoch_distributed
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(
+            epoch_total <= self.epoch_cap,
+            ErrorCode::PoolEpochCapExceeded
+        );
+
+        self.distributed = distributed;
+        self.epoch_distributed = epoch_total;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RewardPoolInput {
+    pub budget: u64,
+    pub epoch_cap: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub issuers: Vec<Pubkey>,
+}
+
+/// Tokens granted to `beneficiary` that unlock linearly between `start_ts`
+/// and `start_ts + duration`, with nothing claimable before `cliff_ts`. The
+/// tokens sit in an escrow ATA owned by this PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    pub beneficiary: Pubkey,
+    pub id: u64,
+    pub total_amount: u64,
+    pub released: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub duration: i64,
+    /// Vesting stops at this time; the unvested rest went back to the vault.
+    pub revoked_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl VestingSchedule {
+    /// Amount unlocked at `now`, whether or not it was claimed yet.
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        let now = self
+            .revoked_at
+            .map_or(now, |revoked_at| now.min(revoked_at));
+        if now < self.cliff_ts {
+            return Ok(0);
+        }
+        let elapsed = now.saturating_sub(self.start_ts);
+        if elapsed >= self.duration {
+            return Ok(self.total_amount);
+        }
+        let vested = (self.total_amount as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            / self.duration as u128;
+        Ok(vested as u64)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VestingInput {
+    pub total_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    /// Seconds from `start_ts` until everything is vested.
+    pub duration: i64,
+}
+
+impl VestingInput {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.total_amount > 0, ErrorCode::InvalidAmount);
+        require!(self.duration > 0, ErrorCode::InvalidVestingSchedule);
+        let end_ts = self
+            .start_ts
+            .checked_add(self.duration)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(
+            self.start_ts <= self.cliff_ts && self.cliff_ts <= end_ts,
+            ErrorCode::InvalidVestingSchedule
+        );
+        Ok(())
+    }
+}
+
+/// Cooldown and multiplier curve for staking. A stake of at least
+/// `full_bonus_amount` held for `full_bonus_duration` earns the full
+/// `max_bonus_bps` on top of every `reward_user` payout; smaller or younger
+/// stakes earn proportionally less.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct StakingParams {
+    /// Seconds between `unstake` and `withdraw`.
+    pub cooldown: i64,
+    pub max_bonus_bps: u64,
+    pub full_bonus_amount: u64,
+    pub full_bonus_duration: i64,
+}
+
+impl StakingParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.cooldown >= 0, ErrorCode::InvalidStakingParams);
+        require!(
+            self.max_bonus_bps <= MAX_STAKE_BONUS_BPS,
+            ErrorCode::InvalidStakingParams
+        );
+        if self.max_bonus_bps > 0 {
+            require!(
+                self.full_bonus_amount > 0 && self.full_bonus_duration > 0,
+                ErrorCode::InvalidStakingParams
+            );
+        }
+        Ok(())
+    }
+
+    /// Reward multiplier in basis points for `stake` at `now`.
+    pub fn multiplier_bps(&self, stake: &StakeAccount, now: i64) -> u64 {
+        if self.max_bonus_bps == 0 {
+            return BPS_DENOMINATOR;
+        }
+        let amount = stake.amount.min(self.full_bonus_amount) as u128;
+        let age = now
+            .saturating_sub(stake.staked_since)
+            .clamp(0, self.full_bonus_duration) as u128;
+        // Scale by amount, then by age; each factor is at most 1.
+        let bonus = self.max_bonus_bps as u128 * amount / self.full_bonus_amount as u128;
+        let bonus = bonus * age / self.full_bonus_duration as u128;
+        BPS_DENOMINATOR + bonus as u64
+    }
+
+    /// Apply the staking multiplier to a payout.
+    pub fn boost(&self, amount: u64, stake: &StakeAccount, now: i64) -> Result<u64> {
+        let boosted =
+            amount as u128 * self.multiplier_bps(stake, now) as u128 / BPS_DENOMINATOR as u128;
+        u64::try_from(boosted).map_err(|_| error!(ErrorCode::MathOverflow))
+    }
+}
+
+/// A user's staked protocol tokens, held in an ATA owned by this PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct StakeAccount {
+    pub owner: Pubkey,
+    /// Actively staked; counts towards the multiplier.
+    pub amount: u64,
+    /// Amount-weighted time the active stake was deposited.
+    pub staked_since: i64,
+    /// Unstaked and cooling down.
+    pub pending_withdrawal: u64,
+    /// When `pending_withdrawal` becomes withdrawable.
+    pub unlock_ts: i64,
+    /// `StakingPool::reward_per_token_stored` at the last checkpoint.
+    pub reward_per_token_paid: u128,
+    /// Staking rewards earned but not yet claimed.
+    pub accrued_rewards: u64,
+    pub bump: u8,
+}
+
+impl StakeAccount {
+    /// Add `amount` to the active stake, averaging `staked_since` by weight.
+    pub fn add(&mut self, amount: u64, now: i64) -> Result<()> {
+        let total = self
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let weighted =
+            self.amount as i128 * self.staked_since as i128 + amount as i128 * now as i128;
+        self.staked_since = (weighted / total as i128) as i64;
+        self.amount = total;
+        Ok(())
+    }
+
+    /// Accrue rewards earned by the active stake since the last checkpoint.
+    /// `pool` must already be updated to the current time.
+    pub fn checkpoint(&mut self, pool: &StakingPool) -> Result<()> {
+        let delta = pool
+            .reward_per_token_stored
+            .checked_sub(self.reward_per_token_paid)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let earned = (self.amount as u128)
+            .checked_mul(delta)
+            .ok_or(ErrorCode::MathOverflow)?
+            / REWARD_PER_TOKEN_PRECISION;
+        self.accrued_rewards = u64::try_from(earned)
+            .ok()
+            .and_then(|earned| self.accrued_rewards.checked_add(earned))
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.reward_per_token_paid = pool.reward_per_token_stored;
+        Ok(())
+    }
+}
+
+/// Streams `emission_rate` tokens per second to stakers in proportion to
+/// their active stake. Each staker's share is the growth of
+/// `reward_per_token_stored` since their own checkpoint, so claims are O(1)
+/// however many stakers there are. Rewards are paid from the pool's own
+/// reward vault ATA and never accrue beyond what was deposited into it.
+#[account]
+#[derive(InitSpace)]
+pub struct StakingPool {
+    /// Cumulative rewards per staked token, scaled by
+    /// `REWARD_PER_TOKEN_PRECISION`.
+    pub reward_per_token_stored: u128,
+    pub last_update_ts: i64,
+    pub emission_rate: u64,
+    pub total_staked: u64,
+    /// Lifetime deposits made through `fund_staking_rewards`.
+    pub total_funded: u64,
+    /// Rewards allocated to stakers so far; never exceeds `total_funded`.
+    pub total_emitted: u64,
+    pub bump: u8,
+}
+
+impl StakingPool {
+    /// Fold emissions since `last_update_ts` into the accumulator, up to
+    /// the rewards still unallocated. Nothing accrues while nobody is staked.
+    pub fn update(&mut self, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.last_update_ts).max(0) as u128;
+        if self.total_staked > 0 && elapsed > 0 {
+            let available = self.total_funded.saturating_sub(self.total_emitted);
+            let emitted = elapsed
+                .checked_mul(self.emission_rate as u128)
+                .ok_or(ErrorCode::MathOverflow)?
+                .min(available as u128);
+            let increment = emitted
+                .checked_mul(REWARD_PER_TOKEN_PRECISION)
+                .ok_or(ErrorCode::MathOverflow)?
+                / self.total_staked as u128;
+            self.reward_per_token_stored = self
+                .reward_per_token_stored
+                .checked_add(increment)
+                .ok_or(ErrorCode::MathOverflow)?;
+            // `emitted <= available`, so this fits in a u64.
+            self.total_emitted += emitted as u64;
+        }
+        self.last_update_ts = self.last_update_ts.max(now);
+        Ok(())
+    }
+}
+
+/// Maps attested activity to a payout. For a given metric the highest tier
+/// whose `min_value` the attested value reaches is paid.
+#[account]
+#[derive(InitSpace)]
+pub struct RewardTable {
+    #[max_len(MAX_REWARD_TIERS)]
+    pub tiers: Vec<RewardTier>,
+    pub bump: u8,
+}
+
+impl RewardTable {
+    pub fn payout(&self, metric: MetricKind, unit: Unit, value: u64) -> Option<u64> {
+        self.tiers
+            .iter()
+            .filter(|t| t.metric == metric && t.unit == unit && value >= t.min_value)
+            .max_by_key(|t| t.min_value)
+            .map(|t| t.reward)
+            .filter(|reward| *reward > 0)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct RewardTier {
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub min_value: u64,
+    pub reward: u64,
+}
+
+/// Marks a (user, metric, period) as paid, by the protocol vault or by one
+/// reward pool.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimReceipt {
+    /// `Pubkey::default()` for payouts from the protocol vault.
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub metric: MetricKind,
+    pub period: i64,
+    pub amount: u64,
+    pub claimed_at: i64,
+    pub bump: u8,
+}
+
+/// Identifies what a `ClaimReceipt` pays for.
+pub struct ClaimKey {
+    /// Pool that pays the claim, or `Pubkey::default()` for the vault.
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub metric: MetricKind,
+    pub period: i64,
+}
+
+impl ClaimReceipt {
+    /// Fill in a receipt that `init_if_needed` just created, failing if it
+    /// was already used, the period is not aligned to `CLAIM_PERIOD` or it
+    /// is outside the claim horizon.
+    pub fn record(
+        &mut self,
+        key: ClaimKey,
+        amount: u64,
+        now: i64,
+        limits: &RewardLimits,
+        bump: u8,
+    ) -> Result<()> {
+        require!(self.claimed_at == 0, ErrorCode::AlreadyClaimed);
+        require!(
+            key.period.rem_euclid(CLAIM_PERIOD) == 0,
+            ErrorCode::MisalignedPeriod
+        );
+        require!(key.period <= now, ErrorCode::PeriodInFuture);
+        require!(
+            limits.within_claim_horizon(key.period, now),
+            ErrorCode::ClaimWindowExpired
+        );
+
+        self.pool = key.pool;
+        self.user = key.user;
+        self.metric = key.metric;
+        self.period = key.period;
+        self.amount = amount;
+        self.claimed_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+/// Activity claim signed off-chain by a registered oracle.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ActivityAttestation {
+    pub user: Pubkey,
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub value: u64,
+    /// Unix timestamp of the start of the period the activity covers, a
+    /// multiple of `CLAIM_PERIOD`.
+    pub period: i64,
+}
+
+impl ActivityAttestation {
+    /// Bytes the oracle signs: the domain prefix followed by the Borsh
+    /// encoding of the attestation.
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = ACTIVITY_ATTESTATION_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserProfile {
+    pub authority: Pubkey,
+    /// Arweave transaction ID, or a CIDv1 when `storage` is `Ipfs`.
+    #[max_len(MAX_STORAGE_ID_LEN)]
+    pub arweave_hash: String,
+    pub created_at: i64,
+    pub storage: StorageKind,
+    /// Index the next health record will be created at.
+    pub record_count: u64,
+    /// Open child accounts (health records, goals, access grants, key
+    /// envelopes, the access log) that must be closed along with the profile.
+    pub child_count: u32,
+    /// Id the next goal will be created with.
+    pub goal_count: u64,
+}
+
+impl UserProfile {
+    pub fn add_child(&mut self) -> Result<()> {
+        self.child_count = self
+            .child_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove_child(&mut self) -> Result<()> {
+        self.child_count = self
+            .child_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// Accounts counted in `UserProfile::child_count`.
+pub trait ProfileChild {
+    fn profile(&self) -> Pubkey;
+}
+
+impl ProfileChild for HealthRecord {
+    fn profile(&self) -> Pubkey {
+        self.profile
+    }
+}
+
+impl ProfileChild for Goal {
+    fn profile(&self) -> Pubkey {
+        self.profile
+    }
+}
+
+impl ProfileChild for AccessGrant {
+    fn profile(&self) -> Pubkey {
+        self.profile
+    }
+}
+
+impl ProfileChild for KeyEnvelope {
+    fn profile(&self) -> Pubkey {
+        self.profile
+    }
+}
+
+/// On-chain anchor for one off-chain health record blob.
+#[account]
+#[derive(InitSpace)]
+pub struct HealthRecord {
+    pub profile: Pubkey,
+    pub index: u64,
+    /// `ar://<tx id>` or `ipfs://<cid>`.
+    #[max_len(MAX_STORAGE_URI_LEN)]
+    pub storage_uri: String,
+    /// SHA-256 of the blob's contents.
+    pub content_hash: [u8; 32],
+    pub metric: MetricKind,
+    pub unit: Unit,
+    /// When the measurement was taken, as reported by the user.
+    pub recorded_at: i64,
+    pub schema_version: u16,
+    pub created_at: i64,
+    /// Index of the record that replaced this one.
+    pub superseded_by: Option<u64>,
+    /// Version of the symmetric key the blob is encrypted under. Only
+    /// `KeyEnvelope`s with the same version can decrypt it.
+    pub key_version: u32,
+    pub bump: u8,
+}
+
+impl HealthRecord {
+    /// Fill in a freshly created record at `profile.record_count` and advance
+    /// the profile's counters.
+    pub fn init(
+        &mut self,
+        profile: &mut Account<UserProfile>,
+        input: HealthRecordInput,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        validate_storage_uri(&input.storage_uri)?;
+        input.metric.check_unit(input.unit)?;
+        require!(input.recorded_at <= now, ErrorCode::RecordedInFuture);
+
+        self.profile = profile.key();
+        self.index = profile.record_count;
+        self.storage_uri = input.storage_uri;
+        self.content_hash = input.content_hash;
+        self.metric = input.metric;
+        self.unit = input.unit;
+        self.recorded_at = input.recorded_at;
+        self.schema_version = input.schema_version;
+        self.created_at = now;
+        self.superseded_by = None;
+        self.key_version = 0;
+        self.bump = bump;
+
+        profile.record_count = profile
+            .record_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        profile.add_child()?;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct HealthRecordInput {
+    pub storage_uri: String,
+    pub content_hash: [u8; 32],
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub recorded_at: i64,
+    pub schema_version: u16,
+}
+
+/// Consent receipt: the profile owner allows `grantee` to access the records
+/// covered by `scope` until `expires_at`.
+#[account]
+#[derive(InitSpace)]
+pub struct AccessGrant {
+    pub profile: Pubkey,
+    pub grantee: Pubkey,
+    pub scope: GrantScope,
+    pub expires_at: i64,
+    pub purpose: PurposeCode,
+    pub mode: GrantMode,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl AccessGrant {
+    pub fn covers(&self, record: &HealthRecord) -> bool {
+        self.scope.metrics.contains(&record.metric)
+            || self.scope.record_indices.contains(&record.index)
+    }
+
+    /// Fails if the grant has expired or has no uses left.
+    pub fn check_usable(&self, now: i64) -> Result<()> {
+        require!(now < self.expires_at, ErrorCode::GrantExpired);
+        require!(!self.mode.is_exhausted(), ErrorCode::GrantExhausted);
+        Ok(())
+    }
+}
+
+/// How an access grant is used up. Every mode is also bounded by
+/// `AccessGrant::expires_at`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum GrantMode {
+    /// Unlimited access until expiry.
+    Expiring,
+    /// Valid for a fixed number of `record_access` calls.
+    LimitedReads { remaining: u32 },
+    /// Valid for a single export.
+    OneTimeExport { used: bool },
+}
+
+impl GrantMode {
+    pub fn validate(&self) -> Result<()> {
+        let valid = match self {
+            GrantMode::Expiring => true,
+            GrantMode::LimitedReads { remaining } => *remaining > 0,
+            GrantMode::OneTimeExport { used } => !used,
+        };
+        require!(valid, ErrorCode::InvalidGrantMode);
+        Ok(())
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        match self {
+            GrantMode::Expiring => false,
+            GrantMode::LimitedReads { remaining } => *remaining == 0,
+            GrantMode::OneTimeExport { used } => *used,
+        }
+    }
+
+    /// Use up one access. Callers check `is_exhausted` first.
+    pub fn consume(&mut self) {
+        match self {
+            GrantMode::Expiring => {}
+            GrantMode::LimitedReads { remaining } => *remaining -= 1,
+            GrantMode::OneTimeExport { used } => *used = true,
+        }
+    }
+}
+
+/// Append-only ring buffer of third-party accesses to a profile's records.
+#[account(zero_copy)]
+pub struct AccessLog {
+    pub profile: Pubkey,
+    /// Entries ever written; the next one goes to
+    /// `total_entries % ACCESS_LOG_CAPACITY`.
+    pub total_entries: u64,
+    pub entries: [AccessLogEntry; ACCESS_LOG_CAPACITY],
+}
+
+impl AccessLog {
+    pub fn push(&mut self, entry: AccessLogEntry) {
+        let slot = (self.total_entries % ACCESS_LOG_CAPACITY as u64) as usize;
+        self.entries[slot] = entry;
+        self.total_entries += 1;
+    }
+}
+
+#[zero_copy]
+pub struct AccessLogEntry {
+    pub grantee: Pubkey,
+    pub grant: Pubkey,
+    pub accessed_at: i64,
+    pub record_index: u64,
+    /// `MetricKind` discriminant of the accessed record.
+    pub metric: u8,
+    /// `PurposeCode` discriminant of the grant.
+    pub purpose: u8,
+    pub _padding: [u8; 6],
+}
+
+/// A record's symmetric key, wrapped for one grantee with X25519 + AEAD.
+#[account]
+#[derive(InitSpace)]
+pub struct KeyEnvelope {
+    pub profile: Pubkey,
+    pub record: Pubkey,
+    pub grantee: Pubkey,
+    /// `HealthRecord::key_version` the wrapped key belongs to.
+    pub key_version: u32,
+    pub ephemeral_pubkey: [u8; 32],
+    #[max_len(MAX_WRAPPED_KEY_LEN)]
+    pub wrapped_key: Vec<u8>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Records covered by a grant: every record of one of `metrics`, plus the
+/// records listed in `record_indices`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct GrantScope {
+    #[max_len(MAX_GRANT_METRICS)]
+    pub metrics: Vec<MetricKind>,
+    #[max_len(MAX_GRANT_RECORDS)]
+    pub record_indices: Vec<u64>,
+}
+
+impl GrantScope {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.metrics.is_empty() || !self.record_indices.is_empty(),
+            ErrorCode::EmptyGrantScope
+        );
+        require!(
+            self.metrics.len() <= MAX_GRANT_METRICS
+                && self.record_indices.len() <= MAX_GRANT_RECORDS,
+            ErrorCode::GrantScopeTooLarge
+        );
+        Ok(())
+    }
+}
+
+/// Why the grantee is being given access.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PurposeCode {
+    Treatment,
+    Research,
+    Coaching,
+    Insurance,
+    Emergency,
+    Other,
+}
+
+/// A measurable target the user commits to, e.g. "at least 8,000 steps
+/// daily between `start_ts` and `end_ts`".
+#[account]
+#[derive(InitSpace)]
+pub struct Goal {
+    pub profile: Pubkey,
+    pub id: u64,
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub target: u64,
+    pub comparator: Comparator,
+    /// Period the target applies to.
+    pub cadence: Cadence,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub status: GoalStatus,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Goal {
+    /// Number of cadence periods in the goal window; a partial trailing
+    /// period counts as a full one.
+    pub fn period_count(&self) -> Result<u32> {
+        let secs = self.cadence.seconds();
+        let periods = self
+            .end_ts
+            .checked_sub(self.start_ts)
+            .and_then(|window| window.checked_add(secs - 1))
+            .ok_or(ErrorCode::MathOverflow)?
+            / secs;
+        Ok(u32::try_from(periods).map_err(|_| ErrorCode::MathOverflow)?)
+    }
+
+    /// Mark an active goal whose window has closed completed if the target
+    /// was met in every period, failed otherwise.
+    pub fn settle(&mut self, periods_met: u32, now: i64) -> Result<()> {
+        require!(self.status == GoalStatus::Active, ErrorCode::GoalNotActive);
+        require!(now >= self.end_ts, ErrorCode::GoalWindowOpen);
+        self.status = if periods_met < self.period_count()? {
+            GoalStatus::Failed
+        } else {
+            GoalStatus::Completed
+        };
+        Ok(())
+    }
+}
+
+/// Oracle statement of a goal's outcome. It repeats the goal's terms so the
+/// oracle signs exactly the goal that is being settled.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GoalAttestation {
+    pub goal: Pubkey,
+    pub user: Pubkey,
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub target: u64,
+    pub comparator: Comparator,
+    pub cadence: Cadence,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    /// Cadence periods within the window in which the target was met.
+    pub periods_met: u32,
+}
+
+impl GoalAttestation {
+    pub fn matches(&self, goal: &Account<Goal>, user: &Pubkey) -> bool {
+        self.goal == goal.key()
+            && self.user == *user
+            && self.metric == goal.metric
+            && self.unit == goal.unit
+            && self.target == goal.target
+            && self.comparator == goal.comparator
+            && self.cadence == goal.cadence
+            && self.start_ts == goal.start_ts
+            && self.end_ts == goal.end_ts
+    }
+
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = GOAL_ATTESTATION_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GoalInput {
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub target: u64,
+    pub comparator: Comparator,
+    pub cadence: Cadence,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+impl GoalInput {
+    /// The window must not have started yet, so activity that already
+    /// happened cannot be claimed as a goal, and must cover at least one full
+    /// cadence period and at most `MAX_GOAL_DURATION`.
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(self.target > 0, ErrorCode::InvalidGoalTarget);
+        require!(self.start_ts >= now, ErrorCode::InvalidGoalWindow);
+        let window = self.end_ts.saturating_sub(self.start_ts);
+        require!(
+            window >= self.cadence.seconds() && window <= MAX_GOAL_DURATION,
+            ErrorCode::InvalidGoalWindow
+        );
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GoalUpdate {
+    pub target: Option<u64>,
+    pub comparator: Option<Comparator>,
+    pub cadence: Option<Cadence>,
+    pub start_ts: Option<i64>,
+    pub end_ts: Option<i64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Comparator {
+    AtLeast,
+    AtMost,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Cadence {
+    Daily,
+    Weekly,
+}
+
+impl Cadence {
+    pub fn seconds(&self) -> i64 {
+        match self {
+            Cadence::Daily => 86_400,
+            Cadence::Weekly => 7 * 86_400,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum GoalStatus {
+    Active,
+    Completed,
+    Abandoned,
+    Failed,
+}
+
+/// A community challenge: participants pay `entry_fee` into a vault owned
+/// by this PDA, and those attested to reach `target` between `start_ts` and
+/// `end_ts` split the pot.
+#[account]
+#[derive(InitSpace)]
+pub struct Challenge {
+    pub creator: Pubkey,
+    pub id: u64,
+    pub metric: MetricKind,
+    pub unit: Unit,
+    /// Total over the window a participant must reach.
+    pub target: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub entry_fee: u64,
+    pub max_participants: u32,
+    pub participant_count: u32,
+    pub winner_count: u32,
+    /// Entry fees collected.
+    pub pot: u64,
+    /// Paid to each claimant, fixed by `settle_challenge`.
+    pub prize: u64,
+    /// Entries closed so far, by claiming or by `close_challenge_entry`.
+    pub closed_entries: u32,
+    pub status: ChallengeStatus,
+    pub bump: u8,
+}
+
+impl Challenge {
+    pub fn results_deadline(&self) -> Result<i64> {
+        self.end_ts
+            .checked_add(CHALLENGE_RESULT_WINDOW)
+            .ok_or(error!(ErrorCode::MathOverflow))
+    }
+
+    /// After this, payouts can no longer be claimed and the creator may
+    /// close the challenge with entries still open.
+    pub fn claim_deadline(&self) -> Result<i64> {
+        self.results_deadline()?
+            .checked_add(CHALLENGE_CLAIM_WINDOW)
+            .ok_or(error!(ErrorCode::MathOverflow))
+    }
+
+    /// Fix the per-claimant payout: an equal share of the pot for each
+    /// winner, or the entry fee back for everyone if nobody won. Integer
+    /// division leaves at most `winner_count - 1` base units of dust in the
+    /// vault, swept by `close_challenge`.
+    pub fn settle(&mut self) {
+        self.prize = if self.winner_count > 0 {
+            self.pot / self.winner_count as u64
+        } else {
+            self.entry_fee
+        };
+        self.status = ChallengeStatus::Settled;
+    }
+
+    /// Whether `entry` collects `prize` on claim: winners do, and so does
+    /// everyone when there are no winners.
+    pub fn is_owed_payout(&self, entry: &ChallengeEntry) -> bool {
+        self.winner_count == 0 || entry.reached_target
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ChallengeStatus {
+    Open,
+    Settled,
+}
+
+/// One participant's seat in a challenge. Closed when they claim, or, for a
+/// loser, by `close_challenge_entry` after settlement.
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeEntry {
+    pub challenge: Pubkey,
+    pub participant: Pubkey,
+    pub reached_target: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChallengeInput {
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub target: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub entry_fee: u64,
+    pub max_participants: u32,
+}
+
+impl ChallengeInput {
+    pub fn validate(&self, now: i64) -> Result<()> {
+        self.metric.check_unit(self.unit)?;
+        require!(self.entry_fee > 0, ErrorCode::InvalidAmount);
+        require!(
+            self.target > 0 && self.max_participants > 0,
+            ErrorCode::InvalidChallenge
+        );
+        require!(
+            now <= self.start_ts && self.start_ts < self.end_ts,
+            ErrorCode::InvalidChallenge
+        );
+        Ok(())
+    }
+}
+
+/// Oracle statement of a participant's total over a challenge window. It
+/// repeats the challenge's terms so the oracle signs exactly the challenge
+/// being judged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChallengeAttestation {
+    pub challenge: Pubkey,
+    pub participant: Pubkey,
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub value: u64,
+}
+
+impl ChallengeAttestation {
+    pub fn matches(&self, challenge: &Account<Challenge>) -> bool {
+        self.challenge == challenge.key()
+            && self.metric == challenge.metric
+            && self.unit == challenge.unit
+            && self.start_ts == challenge.start_ts
+            && self.end_ts == challenge.end_ts
+    }
+
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = CHALLENGE_ATTESTATION_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+/// Canonical taxonomy of health metrics shared by records, attestations and
+/// the reward table. The discriminant is used in PDA seeds, so variants must
+/// only ever be appended.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MetricKind {
+    Steps,
+    SleepMinutes,
+    HeartRate,
+    BloodPressure,
+    Glucose,
+    Weight,
+    Workout,
+    Custom,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Unit {
+    Count,
+    Minutes,
+    BeatsPerMinute,
+    MillimetersOfMercury,
+    MilligramsPerDeciliter,
+    Grams,
+    Kilocalories,
+    Custom,
+}
+
+impl MetricKind {
+    /// Units a value of this metric may be reported in. `Custom` metrics
+    /// accept any unit.
+    pub fn accepts(&self, unit: Unit) -> bool {
+        match self {
+            MetricKind::Steps => unit == Unit::Count,
+            MetricKind::SleepMinutes => unit == Unit::Minutes,
+            MetricKind::HeartRate => unit == Unit::BeatsPerMinute,
+            MetricKind::BloodPressure => unit == Unit::MillimetersOfMercury,
+            MetricKind::Glucose => unit == Unit::MilligramsPerDeciliter,
+            MetricKind::Weight => unit == Unit::Grams,
+            MetricKind::Workout => matches!(unit, Unit::Minutes | Unit::Kilocalories),
+            MetricKind::Custom => true,
+        }
+    }
+
+    pub fn check_unit(&self, unit: Unit) -> Result<()> {
+        require!(self.accepts(unit), ErrorCode::InvalidUnitForMetric);
+        Ok(())
+    }
+}
+
+/// Where the blob referenced by a profile lives.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum StorageKind {
+    Arweave,
+    Ipfs,
+}
+
+impl StorageKind {
+    /// Check that `id` is a well-formed pointer for this storage backend:
+    /// a 43-char base64url transaction ID for Arweave, or a base32 CIDv1
+    /// (multibase prefix `b`) for IPFS.
+    pub fn validate_id(&self, id: &str) -> Result<()> {
+        require!(id.len() <= MAX_STORAGE_ID_LEN, ErrorCode::StorageIdTooLong);
+        match self {
+            StorageKind::Arweave => {
+                require!(
+                    id.len() == ARWEAVE_TX_ID_LEN
+                        && id
+                            .bytes()
+                            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'),
+                    ErrorCode::InvalidArweaveTxId
+                );
+            }
+            StorageKind::Ipfs => {
+                let body = id.strip_prefix('b').ok_or(ErrorCode::InvalidIpfsCid)?;
+                require!(
+                    !body.is_empty()
+                        && body
+                            .bytes()
+                            .all(|b| b.is_ascii_lowercase() || (b'2'..=b'7').contains(&b)),
+                    ErrorCode::InvalidIpfsCid
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_issuers(issuers: &[Pubkey]) -> Result<()> {
+    require!(
+        issuers.len() <= MAX_REWARD_ISSUERS,
+        ErrorCode::TooManyIssuers
+    );
+    for (i, issuer) in issuers.iter().enumerate() {
+        require!(
+            !issuers[..i].contains(issuer),
+            ErrorCode::IssuerAlreadyExists
+        );
+    }
+    Ok(())
+}
+
+fn validate_storage_uri(uri: &str) -> Result<()> {
+    require!(
+        uri.len() <= MAX_STORAGE_URI_LEN,
+        ErrorCode::StorageIdTooLong
+    );
+    if let Some(id) = uri.strip_prefix("ar://") {
+        StorageKind::Arweave.validate_id(id)
+    } else if let Some(id) = uri.strip_prefix("ipfs://") {
+        StorageKind::Ipfs.validate_id(id)
+    } else {
+        err!(ErrorCode::InvalidStorageUri)
+    }
+}
+
+#[event]
+pub struct ProtocolInitialized {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub limits: RewardLimits,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct PauseToggled {
+    pub paused: bool,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct VaultFunded {
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub total_funded: u64,
+}
+
+#[event]
+pub struct EmergencyWithdrawal {
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardPoolEmergencyWithdrawal {
+    pub pool: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardLimitsUpdated {
+    pub limits: RewardLimits,
+}
+
+#[event]
+pub struct GoalRewardUpdated {
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardIssuerAdded {
+    pub issuer: Pubkey,
+}
+
+#[event]
+pub struct RewardIssuerRemoved {
+    pub issuer: Pubkey,
+}
+
+#[event]
+pub struct OracleAdded {
+    pub oracle: Pubkey,
+}
+
+#[event]
+pub struct OracleRemoved {
+    pub oracle: Pubkey,
+}
+
+#[event]
+pub struct RewardTableUpdated {
+    pub tiers: Vec<RewardTier>,
+}
+
+#[event]
+pub struct RewardPaid {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reason: RewardReason,
+    /// Issuer that signed `reward_user`, or the oracle behind an attestation.
+    pub issuer: Pubkey,
+    /// Pool paid from, or `None` for the protocol vault.
+    pub pool: Option<Pubkey>,
+}
+
+#[event]
+pub struct RewardPoolCreated {
+    pub pool: Pubkey,
+    pub id: u64,
+    pub mint: Pubkey,
+    pub budget: u64,
+    pub epoch_cap: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+#[event]
+pub struct RewardPoolIssuersUpdated {
+    pub pool: Pubkey,
+    pub issuers: Vec<Pubkey>,
+}
+
+#[event]
+pub struct RewardPoolFunded {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VestingCreated {
+    pub schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub total_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub duration: i64,
+}
+
+#[event]
+pub struct VestedClaimed {
+    pub schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VestingRevoked {
+    pub schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    /// Unvested amount sent back to the vault.
+    pub returned: u64,
+}
+
+#[event]
+pub struct StakingParamsUpdated {
+    pub params: StakingParams,
+}
+
+#[event]
+pub struct Staked {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct UnstakeStarted {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unlock_ts: i64,
+}
+
+#[event]
+pub struct EmissionRateUpdated {
+    pub emission_rate: u64,
+}
+
+#[event]
+pub struct StakingRewardsFunded {
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct StakingRewardsClaimed {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ChallengeCreated {
+    pub challenge: Pubkey,
+    pub creator: Pubkey,
+    pub id: u64,
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub target: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub entry_fee: u64,
+    pub max_participants: u32,
+}
+
+#[event]
+pub struct ChallengeJoined {
+    pub challenge: Pubkey,
+    pub participant: Pubkey,
+    pub pot: u64,
+}
+
+#[event]
+pub struct ChallengeResultRecorded {
+    pub challenge: Pubkey,
+    pub participant: Pubkey,
+    pub value: u64,
+}
+
+#[event]
+pub struct ChallengeEntryClosed {
+    pub challenge: Pubkey,
+    pub participant: Pubkey,
+}
+
+#[event]
+pub struct ChallengeClosed {
+    pub challenge: Pubkey,
+    /// Rounding remainder and unclaimed payouts swept to the creator.
+    pub swept: u64,
+}
+
+#[event]
+pub struct ChallengeSettled {
+    pub challenge: Pubkey,
+    pub winner_count: u32,
+    pub prize: u64,
+}
+
+#[event]
+pub struct ChallengePrizeClaimed {
+    pub challenge: Pubkey,
+    pub participant: Pubkey,
+    pub amount: u64,
+    /// `true` when nobody won and the entry fee was returned.
+    pub refund: bool,
+}
+
+#[event]
+pub struct StakeWithdrawn {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClaimReceiptClosed {
+    pub user: Pubkey,
+    pub metric: MetricKind,
+    pub period: i64,
+}
+
+#[event]
+pub struct ProfileCreated {
+    pub authority: Pubkey,
+    pub profile: Pubkey,
+    pub storage: StorageKind,
+    pub arweave_hash: String,
+}
+
+#[event]
+pub struct ProfileUpdated {
+    pub authority: Pubkey,
+    pub profile: Pubkey,
+    pub storage: StorageKind,
+    pub arweave_hash: String,
+}
+
+#[event]
+pub struct ProfileDeleted {
+    pub authority: Pubkey,
+    pub profile: Pubkey,
+    pub storage: StorageKind,
+    /// Storage pointer off-chain indexes should purge.
+    pub arweave_hash: String,
+    /// `storage_uri`s of the health records closed with the profile, whose
+    /// blobs should be purged as well.
+    pub record_uris: Vec<String>,
+    /// Number of child accounts closed alongside the profile.
+    pub closed_accounts: u32,
+    pub deleted_at: i64,
+}
+
+#[event]
+pub struct HealthRecordAdded {
+    pub profile: Pubkey,
+    pub record: Pubkey,
+    pub index: u64,
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub storage_uri: String,
+    pub content_hash: [u8; 32],
+    /// Index of the record this one corrects, if any.
+    pub supersedes: Option<u64>,
+}
+
+#[event]
+pub struct RecordKeyShared {
+    pub record: Pubkey,
+    pub grantee: Pubkey,
+    pub key_version: u32,
+}
+
+#[event]
+pub struct RecordKeyRotated {
+    pub record: Pubkey,
+    pub key_version: u32,
+    /// Blob re-encrypted under the new key.
+    pub storage_uri: String,
+    pub content_hash: [u8; 32],
+}
+
+#[event]
+pub struct KeyEnvelopeClosed {
+    pub record: Pubkey,
+    pub grantee: Pubkey,
+}
+
+#[event]
+pub struct GoalCreated {
+    pub profile: Pubkey,
+    pub goal: Pubkey,
+    pub id: u64,
+    pub metric: MetricKind,
+    pub unit: Unit,
+    pub target: u64,
+    pub comparator: Comparator,
+    pub cadence: Cadence,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+#[event]
+pub struct GoalUpdated {
+    pub goal: Pubkey,
+    pub target: u64,
+    pub comparator: Comparator,
+    pub cadence: Cadence,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+#[event]
+pub struct GoalStatusChanged {
+    pub profile: Pubkey,
+    pub goal: Pubkey,
+    pub status: GoalStatus,
+}
+
+#[event]
+pub struct GoalClosed {
+    pub profile: Pubkey,
+    pub goal: Pubkey,
+}
+
+#[event]
+pub struct AccessGranted {
+    pub profile: Pubkey,
+    pub grantee: Pubkey,
+    pub grant: Pubkey,
+    pub scope: GrantScope,
+    pub expires_at: i64,
+    pub purpose: PurposeCode,
+    pub mode: GrantMode,
+}
+
+#[event]
+pub struct AccessRevoked {
+    pub profile: Pubkey,
+    pub grantee: Pubkey,
+    pub grant: Pubkey,
+    /// `true` when closed by `close_expired_grant` rather than the owner.
+    pub expired: bool,
+}
+
+#[event]
+pub struct AccessLogCreated {
+    pub profile: Pubkey,
+    pub access_log: Pubkey,
+}
+
+#[event]
+pub struct RecordAccessed {
+    pub profile: Pubkey,
+    pub grantee: Pubkey,
+    pub grant: Pubkey,
+    pub record: Pubkey,
+    pub record_index: u64,
+    pub metric: MetricKind,
+    pub purpose: PurposeCode,
+    pub accessed_at: i64,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Signer is not the protocol admin")]
+    Unauthorized,
+    #[msg("Mint does not match the protocol mint")]
+    InvalidMint,
+    #[msg("Signer is not an authorized reward issuer")]
+    UnauthorizedIssuer,
+    #[msg("Too many reward issuers")]
+    TooManyIssuers,
+    #[msg("Reward issuer is already registered")]
+    IssuerAlreadyExists,
+    #[msg("Reward issuer is not registered")]
+    IssuerNotFound,
+    #[msg("Epoch duration must be greater than zero")]
+    InvalidEpochDuration,
+    #[msg("Per-user cap cannot exceed the global cap")]
+    InvalidRewardLimits,
+    #[msg("Reward would exceed the user's cap for this epoch")]
+    UserRewardCapExceeded,
+    #[msg("Reward would exceed the global cap for this epoch")]
+    GlobalRewardCapExceeded,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Too many oracles")]
+    TooManyOracles,
+    #[msg("Oracle is already registered")]
+    OracleAlreadyExists,
+    #[msg("Oracle is not registered")]
+    OracleNotFound,
+    #[msg("Too many reward tiers")]
+    TooManyRewardTiers,
+    #[msg("Attestation was issued for a different user")]
+    AttestationUserMismatch,
+    #[msg("Expected an Ed25519 signature instruction before this one")]
+    MissingEd25519Instruction,
+    #[msg("Malformed Ed25519 signature instruction")]
+    InvalidEd25519Instruction,
+    #[msg("Signed message does not match the attestation")]
+    AttestationMismatch,
+    #[msg("Attestation was not signed by a registered oracle")]
+    UnknownOracle,
+    #[msg("Attested activity does not qualify for a reward")]
+    NoRewardForActivity,
+    #[msg("Claim horizon must be greater than zero")]
+    InvalidClaimHorizon,
+    #[msg("Reward for this period has already been claimed")]
+    AlreadyClaimed,
+    #[msg("Period has not started yet")]
+    PeriodInFuture,
+    #[msg("Period is older than the claim horizon")]
+    ClaimWindowExpired,
+    #[msg("Claim receipt is still within the claim horizon")]
+    ClaimReceiptStillActive,
+    #[msg("Storage pointer is too long")]
+    StorageIdTooLong,
+    #[msg("Not a valid 43-character base64url Arweave transaction ID")]
+    InvalidArweaveTxId,
+    #[msg("Not a valid base32 CIDv1")]
+    InvalidIpfsCid,
+    #[msg("Account is not a closable child of this profile")]
+    InvalidChildAccount,
+    #[msg("Child account belongs to a different user")]
+    ChildAccountMismatch,
+    #[msg("Reward ledger cannot be closed until its epoch ends")]
+    RewardLedgerWindowOpen,
+    #[msg("All child accounts must be closed with the profile")]
+    ProfileHasOpenAccounts,
+    #[msg("Storage URI must start with ar:// or ipfs://")]
+    InvalidStorageUri,
+    #[msg("Record timestamp is in the future")]
+    RecordedInFuture,
+    #[msg("Record has already been superseded")]
+    RecordAlreadySuperseded,
+    #[msg("Unit is not valid for this metric")]
+    InvalidUnitForMetric,
+    #[msg("Goal target must be greater than zero")]
+    InvalidGoalTarget,
+    #[msg("Goal must end in the future and span at least one cadence period")]
+    InvalidGoalWindow,
+    #[msg("Goal is not active")]
+    GoalNotActive,
+    #[msg("Goal window has already started")]
+    GoalAlreadyStarted,
+    #[msg("Goal window has not ended yet")]
+    GoalWindowOpen,
+    #[msg("Goal is still active")]
+    GoalStillActive,
+    #[msg("Grant must cover at least one metric or record")]
+    EmptyGrantScope,
+    #[msg("Grant scope lists too many metrics or records")]
+    GrantScopeTooLarge,
+    #[msg("Grant expiry must be in the future")]
+    InvalidGrantExpiry,
+    #[msg("Wrapped key is empty or too long")]
+    InvalidWrappedKey,
+    #[msg("Access grant has expired")]
+    GrantExpired,
+    #[msg("Record is not covered by the access grant")]
+    RecordNotInGrantScope,
+    #[msg("Read-limited grants need at least one read; exports must start unused")]
+    InvalidGrantMode,
+    #[msg("Access grant has no uses left")]
+    GrantExhausted,
+    #[msg("Access grant is still active")]
+    GrantStillActive,
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+    #[msg("Account is not the configured treasury")]
+    InvalidTreasury,
+    #[msg("Reward pool must end after it starts")]
+    InvalidPoolWindow,
+    #[msg("Reward pool is not active")]
+    PoolNotActive,
+    #[msg("Reward pool budget exceeded")]
+    PoolBudgetExceeded,
+    #[msg("Vesting cliff must fall within a non-empty vesting period")]
+    InvalidVestingSchedule,
+    #[msg("Nothing has vested since the last claim")]
+    NothingToClaim,
+    #[msg("Vesting schedule was already revoked")]
+    VestingAlreadyRevoked,
+    #[msg("Invalid staking parameters")]
+    InvalidStakingParams,
+    #[msg("Not enough tokens staked")]
+    InsufficientStake,
+    #[msg("Nothing is waiting to be withdrawn")]
+    NothingToWithdraw,
+    #[msg("Unstake cooldown has not elapsed")]
+    CooldownActive,
+    #[msg("Invalid challenge terms")]
+    InvalidChallenge,
+    #[msg("Challenge has already started")]
+    ChallengeAlreadyStarted,
+    #[msg("Challenge is full")]
+    ChallengeFull,
+    #[msg("Challenge has not ended yet")]
+    ChallengeNotEnded,
+    #[msg("Challenge is not open")]
+    ChallengeNotOpen,
+    #[msg("Challenge results can no longer be recorded")]
+    ChallengeResultWindowClosed,
+    #[msg("Challenge results can still be recorded")]
+    ChallengeResultWindowOpen,
+    #[msg("Attested value does not reach the challenge target")]
+    ChallengeTargetNotReached,
+    #[msg("Challenge result already recorded")]
+    ChallengeResultAlreadyRecorded,
+    #[msg("Challenge has not been settled")]
+    ChallengeNotSettled,
+    #[msg("Participant did not reach the challenge target")]
+    NotAChallengeWinner,
+    #[msg("Reward pool epoch cap exceeded")]
+    PoolEpochCapExceeded,
+    #[msg("Claim period must start on a CLAIM_PERIOD boundary")]
+    MisalignedPeriod,
+    #[msg("Claim horizon cannot be increased")]
+    ClaimHorizonIncreased,
+    #[msg("Challenge entry is owed a payout; claim it instead")]
+    ChallengeEntryClaimable,
+    #[msg("Challenge still has open entries")]
+    ChallengeEntriesOpen,
+    #[msg("Challenge claim window has closed")]
+    ChallengeClaimWindowClosed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+
+    fn limits() -> RewardLimits {
+        RewardLimits {
+            epoch_duration: DAY,
+            user_epoch_cap: 100,
+            global_epoch_cap: 250,
+            claim_horizon: 7 * DAY,
+        }
+    }
+
+    fn error_code(err: Error) -> u32 {
+        match err {
+            Error::AnchorError(e) => e.error_code_number,
+            Error::ProgramError(e) => panic!("unexpected program error: {e}"),
+        }
+    }
+
+    fn assert_err<T: std::fmt::Debug>(result: Result<T>, code: ErrorCode) {
+        assert_eq!(error_code(result.unwrap_err()), u32::from(code));
+    }
+
+    fn receipt() -> ClaimReceipt {
+        ClaimReceipt {
+            pool: Pubkey::default(),
+            user: Pubkey::default(),
+            metric: MetricKind::Steps,
+            period: 0,
+            amount: 0,
+            claimed_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn claim(period: i64) -> ClaimKey {
+        ClaimKey {
+            pool: Pubkey::default(),
+            user: Pubkey::new_unique(),
+            metric: MetricKind::Steps,
+            period,
+        }
+    }
+
+    #[test]
+    fn claim_receipt_requires_aligned_period() {
+        let now = 100 * DAY + 3_600;
+        let mut r = receipt();
+        assert_err(
+            r.record(claim(100 * DAY - 1), 1, now, &limits(), 0),
+            ErrorCode::MisalignedPeriod,
+        );
+        assert_err(
+            r.record(claim(99 * DAY + 1), 1, now, &limits(), 0),
+            ErrorCode::MisalignedPeriod,
+        );
+        r.record(claim(100 * DAY), 1, now, &limits(), 0).unwrap();
+        assert_eq!(r.claimed_at, now);
+    }
+
+    #[test]
+    fn claim_receipt_rejects_reuse_future_and_expired_periods() {
+        let now = 100 * DAY + 3_600;
+        let mut r = receipt();
+        r.record(claim(100 * DAY), 1, now, &limits(), 0).unwrap();
+        assert_err(
+            r.record(claim(100 * DAY), 1, now, &limits(), 0),
+            ErrorCode::AlreadyClaimed,
+        );
+        assert_err(
+            receipt().record(claim(101 * DAY), 1, now, &limits(), 0),
+            ErrorCode::PeriodInFuture,
+        );
+        assert_err(
+            receipt().record(claim(92 * DAY), 1, now, &limits(), 0),
+            ErrorCode::ClaimWindowExpired,
+        );
+    }
+
+    fn config() -> ProtocolConfig {
+        ProtocolConfig {
+            admin: Pubkey::default(),
+            mint: Pubkey::default(),
+            issuers: vec![],
+            oracles: vec![],
+            limits: limits(),
+            epoch_start: 0,
+            epoch_distributed: 0,
+            goal_reward: 0,
+            guardian: Pubkey::default(),
+            treasury: Pubkey::default(),
+            paused: false,
+            total_funded: 0,
+            total_distributed: 0,
+            total_withdrawn: 0,
+            staking: StakingParams::default(),
+            bump: 0,
+        }
+    }
+
+    fn ledger() -> RewardLedger {
+        RewardLedger {
+            authority: Pubkey::new_unique(),
+            window_start: 0,
+            claimed_in_window: 0,
+            earned_by_reason: [0; REWARD_REASON_COUNT],
+            month_start: 0,
+            earned_this_month: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn record_emission_enforces_user_cap_within_epoch() {
+        let mut config = config();
+        let mut alice = ledger();
+        let now = 10 * DAY + 100;
+
+        config
+            .record_emission(&mut alice, 60, RewardReason::DailyLog, now)
+            .unwrap();
+        config
+            .record_emission(&mut alice, 40, RewardReason::DailyLog, now + 10)
+            .unwrap();
+        assert_err(
+            config.record_emission(&mut alice, 1, RewardReason::DailyLog, now + 20),
+            ErrorCode::UserRewardCapExceeded,
+        );
+
+        // A rejected payout leaves every counter untouched.
+        assert_eq!(alice.claimed_in_window, 100);
+        assert_eq!(config.epoch_distributed, 100);
+        assert_eq!(config.total_distributed, 100);
+        assert_eq!(alice.earned_by_reason[RewardReason::DailyLog as usize], 100);
+    }
+
+    #[test]
+    fn record_emission_enforces_global_cap_across_users() {
+        let mut config = config();
+        let now = 10 * DAY;
+        let (mut a, mut b, mut c) = (ledger(), ledger(), ledger());
+
+        config
+            .record_emission(&mut a, 100, RewardReason::DailyLog, now)
+            .unwrap();
+        config
+            .record_emission(&mut b, 100, RewardReason::DailyLog, now)
+            .unwrap();
+        assert_err(
+            config.record_emission(&mut c, 51, RewardReason::DailyLog, now),
+            ErrorCode::GlobalRewardCapExceeded,
+        );
+        assert_eq!(c.claimed_in_window, 0);
+        config
+            .record_emission(&mut c, 50, RewardReason::DailyLog, now)
+            .unwrap();
+        assert_eq!(config.epoch_distributed, 250);
+    }
+
+    #[test]
+    fn record_emission_rolls_windows_over_each_epoch() {
+        let mut config = config();
+        let mut alice = ledger();
+        let mut bob = ledger();
+        let day = 10 * DAY;
+
+        config
+            .record_emission(&mut alice, 100, RewardReason::DailyLog, day + 5)
+            .unwrap();
+        config
+            .record_emission(&mut bob, 100, RewardReason::DailyLog, day + 5)
+            .unwrap();
+
+        // The last second of the epoch still belongs to it.
+        assert_err(
+            config.record_emission(&mut alice, 1, RewardReason::DailyLog, day + DAY - 1),
+            ErrorCode::UserRewardCapExceeded,
+        );
+
+        config
+            .record_emission(&mut alice, 100, RewardReason::DailyLog, day + DAY)
+            .unwrap();
+        assert_eq!(alice.window_start, day + DAY);
+        assert_eq!(alice.claimed_in_window, 100);
+        assert_eq!(config.epoch_start, day + DAY);
+        assert_eq!(config.epoch_distributed, 100);
+        assert_eq!(config.total_distributed, 300);
+
+        // Bob's stale window resets on his next payout.
+        config
+            .record_emission(&mut bob, 100, RewardReason::DailyLog, day + DAY + 1)
+            .unwrap();
+        assert_eq!(bob.claimed_in_window, 100);
+    }
+
+    /// Ed25519 program data carrying one signature over `message` by
+    /// `signer`, with every offset pointing at `data_ix`.
+    fn ed25519_data(signer: &Pubkey, message: &[u8], data_ix: u16) -> Vec<u8> {
+        let pubkey_offset = (ED25519_HEADER_LEN + ED25519_OFFSETS_LEN) as u16;
+        let signature_offset = pubkey_offset + 32;
+        let message_offset = signature_offset + 64;
+        let mut data = vec![1, 0];
+        for field in [
+            signature_offset,
+            data_ix,
+            pubkey_offset,
+            data_ix,
+            message_offset,
+            message.len() as u16,
+            data_ix,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    /// Serialize `ixs` the way the runtime lays out the instructions sysvar,
+    /// with `current` as the executing instruction.
+    fn sysvar_data(ixs: &[(Pubkey, Vec<u8>)], current: u16) -> Vec<u8> {
+        let mut data = (ixs.len() as u16).to_le_bytes().to_vec();
+        data.resize(2 + 2 * ixs.len(), 0);
+        for (i, (program_id, ix_data)) in ixs.iter().enumerate() {
+            let start = data.len() as u16;
+            data[2 + 2 * i..4 + 2 * i].copy_from_slice(&start.to_le_bytes());
+            data.extend_from_slice(&0u16.to_le_bytes());
+            data.extend_from_slice(program_id.as_ref());
+            data.extend_from_slice(&(ix_data.len() as u16).to_le_bytes());
+            data.extend_from_slice(ix_data);
+        }
+        data.extend_from_slice(&current.to_le_bytes());
+        data
+    }
+
+    /// Run `f` against an instructions sysvar account holding `ixs`.
+    fn with_sysvar<T>(
+        ixs: &[(Pubkey, Vec<u8>)],
+        current: u16,
+        f: impl FnOnce(&AccountInfo) -> T,
+    ) -> T {
+        let key = instructions_sysvar::ID;
+        let owner = Pubkey::default();
+        let mut lamports = 0;
+        let mut data = sysvar_data(ixs, current);
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        f(&info)
+    }
+
+    fn verify(ixs: &[(Pubkey, Vec<u8>)], current: u16, message: &[u8]) -> Result<Pubkey> {
+        with_sysvar(ixs, current, |info| {
+            verify_ed25519_attestation(info, message)
+        })
+    }
+
+    fn program_ix(data: Vec<u8>) -> (Pubkey, Vec<u8>) {
+        (crate::ID, data)
+    }
+
+    #[test]
+    fn ed25519_attestation_returns_signer() {
+        let oracle = Pubkey::new_unique();
+        let ixs = [
+            (
+                ed25519_program::ID,
+                ed25519_data(&oracle, b"attest", u16::MAX),
+            ),
+            program_ix(vec![]),
+        ];
+        assert_eq!(verify(&ixs, 1, b"attest").unwrap(), oracle);
+    }
+
+    #[test]
+    fn ed25519_attestation_requires_preceding_precompile() {
+        let oracle = Pubkey::new_unique();
+        assert_err(
+            verify(&[program_ix(vec![])], 0, b"attest"),
+            ErrorCode::MissingEd25519Instruction,
+        );
+        // A look-alike payload from another program does not count.
+        let ixs = [
+            program_ix(ed25519_data(&oracle, b"attest", u16::MAX)),
+            program_ix(vec![]),
+        ];
+        assert_err(
+            verify(&ixs, 1, b"attest"),
+            ErrorCode::MissingEd25519Instruction,
+        );
+    }
+
+    #[test]
+    fn ed25519_attestation_rejects_offsets_into_other_instructions() {
+        let oracle = Pubkey::new_unique();
+        for data_ix in [0, 1] {
+            let ixs = [
+                (
+                    ed25519_program::ID,
+                    ed25519_data(&oracle, b"attest", data_ix),
+                ),
+                program_ix(vec![]),
+            ];
+            assert_err(
+                verify(&ixs, 1, b"attest"),
+                ErrorCode::InvalidEd25519Instruction,
+            );
+        }
+    }
+
+    #[test]
+    fn ed25519_attestation_rejects_other_message() {
+        let oracle = Pubkey::new_unique();
+        let ixs = [
+            (
+                ed25519_program::ID,
+                ed25519_data(&oracle, b"attest", u16::MAX),
+            ),
+            program_ix(vec![]),
+        ];
+        assert_err(verify(&ixs, 1, b"attess"), ErrorCode::AttestationMismatch);
+    }
+
+    #[test]
+    fn oracle_attestation_requires_registered_oracle() {
+        let oracle = Pubkey::new_unique();
+        let ixs = [
+            (
+                ed25519_program::ID,
+                ed25519_data(&oracle, b"attest", u16::MAX),
+            ),
+            program_ix(vec![]),
+        ];
+        let mut config = config();
+        with_sysvar(&ixs, 1, |info| {
+            assert_err(
+                verify_oracle_attestation(&config, info, b"attest"),
+                ErrorCode::UnknownOracle,
+            );
+            config.oracles.push(oracle);
+            assert_eq!(
+                verify_oracle_attestation(&config, info, b"attest").unwrap(),
+                oracle
+            );
+        });
+    }
+
+    /// 1_000 tokens over 100s from t=1_000, with a cliff at t=1_025.
+    fn schedule() -> VestingSchedule {
+        VestingSchedule {
+            beneficiary: Pubkey::new_unique(),
+            id: 0,
+            total_amount: 1_000,
+            released: 0,
+            start_ts: 1_000,
+            cliff_ts: 1_025,
+            duration: 100,
+            revoked_at: None,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn vested_amount_is_zero_before_cliff() {
+        let s = schedule();
+        assert_eq!(s.vested_amount(0).unwrap(), 0);
+        assert_eq!(s.vested_amount(1_000).unwrap(), 0);
+        assert_eq!(s.vested_amount(1_024).unwrap(), 0);
+    }
+
+    #[test]
+    fn vested_amount_is_linear_from_start_after_cliff() {
+        let s = schedule();
+        // Reaching the cliff releases everything accrued since `start_ts`.
+        assert_eq!(s.vested_amount(1_025).unwrap(), 250);
+        assert_eq!(s.vested_amount(1_050).unwrap(), 500);
+        assert_eq!(s.vested_amount(1_099).unwrap(), 990);
+    }
+
+    #[test]
+    fn vested_amount_is_full_at_and_after_end() {
+        let s = schedule();
+        assert_eq!(s.vested_amount(1_100).unwrap(), 1_000);
+        assert_eq!(s.vested_amount(i64::MAX).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn vested_amount_freezes_at_revocation() {
+        let mut s = schedule();
+        s.revoked_at = Some(1_040);
+        assert_eq!(s.vested_amount(1_030).unwrap(), 300);
+        assert_eq!(s.vested_amount(1_040).unwrap(), 400);
+        assert_eq!(s.vested_amount(5_000).unwrap(), 400);
+
+        // Revoked before the cliff, nothing ever vests.
+        s.revoked_at = Some(1_010);
+        assert_eq!(s.vested_amount(5_000).unwrap(), 0);
+    }
+
+    fn staking_pool(emission_rate: u64, total_funded: u64) -> StakingPool {
+        StakingPool {
+            reward_per_token_stored: 0,
+            last_update_ts: 0,
+            emission_rate,
+            total_staked: 0,
+            total_funded,
+            total_emitted: 0,
+            bump: 0,
+        }
+    }
+
+    fn stake_account() -> StakeAccount {
+        StakeAccount {
+            owner: Pubkey::new_unique(),
+            amount: 0,
+            staked_since: 0,
+            pending_withdrawal: 0,
+            unlock_ts: 0,
+            reward_per_token_paid: 0,
+            accrued_rewards: 0,
+            bump: 0,
+        }
+    }
+
+    /// Mirror of the `stake` handler's bookkeeping.
+    fn enter(pool: &mut StakingPool, stake: &mut StakeAccount, amount: u64, now: i64) {
+        pool.update(now).unwrap();
+        stake.checkpoint(pool).unwrap();
+        stake.add(amount, now).unwrap();
+        pool.total_staked += amount;
+    }
+
+    #[test]
+    fn staking_rewards_split_by_stake_and_time() {
+        let mut pool = staking_pool(10, 1_000_000);
+        let (mut alice, mut bob) = (stake_account(), stake_account());
+
+        // Alice is alone for the first 100s, then Bob joins with 3x her stake.
+        enter(&mut pool, &mut alice, 100, 0);
+        enter(&mut pool, &mut bob, 300, 100);
+        assert_eq!(bob.accrued_rewards, 0);
+
+        pool.update(200).unwrap();
+        alice.checkpoint(&pool).unwrap();
+        bob.checkpoint(&pool).unwrap();
+        assert_eq!(alice.accrued_rewards, 1_000 + 250);
+        assert_eq!(bob.accrued_rewards, 750);
+        assert_eq!(pool.total_emitted, 2_000);
+
+        // Checkpointing twice at the same time earns nothing extra.
+        alice.checkpoint(&pool).unwrap();
+        assert_eq!(alice.accrued_rewards, 1_250);
+    }
+
+    #[test]
+    fn staking_rewards_nothing_accrues_while_empty() {
+        let mut pool = staking_pool(10, 1_000_000);
+        let mut alice = stake_account();
+        pool.update(500).unwrap();
+        enter(&mut pool, &mut alice, 100, 500);
+
+        pool.update(600).unwrap();
+        alice.checkpoint(&pool).unwrap();
+        assert_eq!(alice.accrued_rewards, 1_000);
+        assert_eq!(pool.total_emitted, 1_000);
+    }
+
+    #[test]
+    fn staking_rewards_never_exceed_funding() {
+        let mut pool = staking_pool(10, 1_500);
+        let (mut alice, mut bob) = (stake_account(), stake_account());
+        enter(&mut pool, &mut alice, 100, 0);
+        enter(&mut pool, &mut bob, 100, 0);
+
+        pool.update(1_000).unwrap();
+        assert_eq!(pool.total_emitted, 1_500);
+        pool.update(2_000).unwrap();
+        alice.checkpoint(&pool).unwrap();
+        bob.checkpoint(&pool).unwrap();
+        assert_eq!(alice.accrued_rewards + bob.accrued_rewards, 1_500);
+
+        // Topping up resumes emissions from the time of the deposit, not
+        // retroactively.
+        pool.update(3_000).unwrap();
+        pool.total_funded += 1_000;
+        pool.update(3_010).unwrap();
+        assert_eq!(pool.total_emitted, 1_600);
+    }
+
+    /// Up to +50% for 1_000 tokens staked for 100s.
+    fn staking_params() -> StakingParams {
+        StakingParams {
+            cooldown: 0,
+            max_bonus_bps: 5_000,
+            full_bonus_amount: 1_000,
+            full_bonus_duration: 100,
+        }
+    }
+
+    fn staked(amount: u64, since: i64) -> StakeAccount {
+        StakeAccount {
+            amount,
+            staked_since: since,
+            ..stake_account()
+        }
+    }
+
+    #[test]
+    fn multiplier_bps_scales_with_amount_and_age() {
+        let params = staking_params();
+        assert_eq!(params.multiplier_bps(&staked(0, 0), 100), 10_000);
+        assert_eq!(params.multiplier_bps(&staked(1_000, 0), 0), 10_000);
+        assert_eq!(params.multiplier_bps(&staked(1_000, 0), 50), 12_500);
+        assert_eq!(params.multiplier_bps(&staked(500, 0), 100), 12_500);
+        assert_eq!(params.multiplier_bps(&staked(250, 0), 50), 10_625);
+        assert_eq!(params.multiplier_bps(&staked(1_000, 0), 100), 15_000);
+    }
+
+    #[test]
+    fn multiplier_bps_is_capped_and_never_below_one() {
+        let params = staking_params();
+        assert_eq!(
+            params.multiplier_bps(&staked(u64::MAX, i64::MIN), i64::MAX),
+            15_000
+        );
+        // A clock behind `staked_since` earns no bonus rather than a malus.
+        assert_eq!(params.multiplier_bps(&staked(1_000, 100), 0), 10_000);
+        assert_eq!(
+            StakingParams::default().multiplier_bps(&staked(1_000, 0), 100),
+            10_000
+        );
+    }
+
+    #[test]
+    fn boost_applies_multiplier() {
+        let params = staking_params();
+        assert_eq!(params.boost(200, &staked(1_000, 0), 100).unwrap(), 300);
+        assert_eq!(params.boost(3, &staked(1_000, 0), 50).unwrap(), 3);
+    }
+
+    /// Three participants paying 100 each.
+    fn challenge(winner_count: u32) -> Challenge {
+        Challenge {
+            creator: Pubkey::new_unique(),
+            id: 0,
+            metric: MetricKind::Steps,
+            unit: Unit::Count,
+            target: 10_000,
+            start_ts: 0,
+            end_ts: DAY,
+            entry_fee: 100,
+            max_participants: 10,
+            participant_count: 3,
+            winner_count,
+            pot: 300,
+            prize: 0,
+            closed_entries: 0,
+            status: ChallengeStatus::Open,
+            bump: 0,
+        }
+    }
+
+    fn entry(reached_target: bool) -> ChallengeEntry {
+        ChallengeEntry {
+            challenge: Pubkey::new_unique(),
+            participant: Pubkey::new_unique(),
+            reached_target,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn challenge_settle_splits_pot_between_winners() {
+        let mut c = challenge(2);
+        c.settle();
+        assert!(c.status == ChallengeStatus::Settled);
+        assert_eq!(c.prize, 150);
+        assert!(c.is_owed_payout(&entry(true)));
+        assert!(!c.is_owed_payout(&entry(false)));
+    }
+
+    #[test]
+    fn challenge_settle_leaves_dust_below_winner_count() {
+        let mut c = challenge(3);
+        c.pot = 301;
+        c.settle();
+        assert_eq!(c.prize, 100);
+        assert_eq!(c.pot - c.prize * c.winner_count as u64, 1);
+    }
+
+    #[test]
+    fn challenge_settle_refunds_everyone_without_winners() {
+        let mut c = challenge(0);
+        c.settle();
+        assert_eq!(c.prize, c.entry_fee);
+        assert!(c.is_owed_payout(&entry(false)));
+        assert_eq!(c.prize * c.participant_count as u64, c.pot);
+    }
+
+    #[test]
+    fn challenge_results_deadline_follows_end() {
+        let c = challenge(0);
+        assert_eq!(c.results_deadline().unwrap(), DAY + CHALLENGE_RESULT_WINDOW);
+        let mut c = c;
+        c.end_ts = i64::MAX;
+        assert_err(c.results_deadline(), ErrorCode::MathOverflow);
+    }
+
+    #[test]
+    fn challenge_claim_deadline_follows_result_window() {
+        let c = challenge(0);
+        assert_eq!(
+            c.claim_deadline().unwrap(),
+            c.results_deadline().unwrap() + CHALLENGE_CLAIM_WINDOW
+        );
+        let mut c = c;
+        c.end_ts = i64::MAX - CHALLENGE_RESULT_WINDOW;
+        assert_err(c.claim_deadline(), ErrorCode::MathOverflow);
+    }
+
+    const ARWEAVE_ID: &str = "AAAAAAAAAAAAAAAAAAAAA-_zzzzzzzzzzzzzzzzzzzz";
+    const IPFS_CID: &str = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+    #[test]
+    fn storage_validate_id_accepts_well_formed_ids() {
+        StorageKind::Arweave.validate_id(ARWEAVE_ID).unwrap();
+        StorageKind::Ipfs.validate_id(IPFS_CID).unwrap();
+    }
+
+    #[test]
+    fn storage_validate_id_rejects_malformed_arweave_ids() {
+        assert_err(
+            StorageKind::Arweave.validate_id(&ARWEAVE_ID[1..]),
+            ErrorCode::InvalidArweaveTxId,
+        );
+        let bad = ARWEAVE_ID.replace('-', "+");
+        assert_err(
+            StorageKind::Arweave.validate_id(&bad),
+            ErrorCode::InvalidArweaveTxId,
+        );
+        assert_err(
+            StorageKind::Arweave.validate_id(IPFS_CID),
+            ErrorCode::InvalidArweaveTxId,
+        );
+    }
+
+    #[test]
+    fn storage_validate_id_rejects_malformed_cids() {
+        for bad in ["", "b", "Qmabc", "bafyBEIG", "bafy0", ARWEAVE_ID] {
+            assert_err(
+                StorageKind::Ipfs.validate_id(bad),
+                ErrorCode::InvalidIpfsCid,
+            );
+        }
+    }
+
+    #[test]
+    fn storage_validate_id_caps_length() {
+        let long = format!("b{}", "a".repeat(MAX_STORAGE_ID_LEN));
+        assert_err(
+            StorageKind::Ipfs.validate_id(&long),
+            ErrorCode::StorageIdTooLong,
+        );
+        StorageKind::Ipfs
+            .validate_id(&long[..MAX_STORAGE_ID_LEN])
+            .unwrap();
+    }
+
+    #[test]
+    fn storage_uri_dispatches_on_scheme() {
+        validate_storage_uri(&format!("ar://{ARWEAVE_ID}")).unwrap();
+        validate_storage_uri(&format!("ipfs://{IPFS_CID}")).unwrap();
+        assert_err(
+            validate_storage_uri(&format!("ar://{IPFS_CID}")),
+            ErrorCode::InvalidArweaveTxId,
+        );
+        assert_err(
+            validate_storage_uri(&format!("ipfs://{ARWEAVE_ID}")),
+            ErrorCode::InvalidIpfsCid,
+        );
+        assert_err(
+            validate_storage_uri(&format!("https://{ARWEAVE_ID}")),
+            ErrorCode::InvalidStorageUri,
+        );
+        let long = format!("ipfs://b{}", "a".repeat(MAX_STORAGE_URI_LEN));
+        assert_err(validate_storage_uri(&long), ErrorCode::StorageIdTooLong);
+    }
+
+    fn profile() -> UserProfile {
+        UserProfile {
+            authority: Pubkey::default(),
+            arweave_hash: ARWEAVE_ID.to_string(),
+            created_at: 0,
+            storage: StorageKind::Arweave,
+            record_count: 0,
+            child_count: 0,
+            goal_count: 0,
+        }
+    }
+
+    #[test]
+    fn profile_child_count_tracks_adds_and_removes() {
+        let mut p = profile();
+        p.add_child().unwrap();
+        p.add_child().unwrap();
+        p.remove_child().unwrap();
+        assert_eq!(p.child_count, 1);
+        p.remove_child().unwrap();
+        assert_err(p.remove_child(), ErrorCode::MathOverflow);
+        assert_eq!(p.child_count, 0);
+    }
+
+    #[test]
+    fn profile_child_count_does_not_wrap() {
+        let mut p = profile();
+        p.child_count = u32::MAX;
+        assert_err(p.add_child(), ErrorCode::MathOverflow);
+    }
+
+    #[test]
+    fn metric_accepts_only_its_units() {
+        MetricKind::Steps.check_unit(Unit::Count).unwrap();
+        assert_err(
+            MetricKind::Steps.check_unit(Unit::Minutes),
+            ErrorCode::InvalidUnitForMetric,
+        );
+        assert!(MetricKind::Workout.accepts(Unit::Minutes));
+        assert!(MetricKind::Workout.accepts(Unit::Kilocalories));
+        assert!(!MetricKind::Workout.accepts(Unit::Count));
+        assert!(MetricKind::Custom.accepts(Unit::Grams));
+    }
+
+    fn goal_input(cadence: Cadence, start_ts: i64, end_ts: i64) -> GoalInput {
+        GoalInput {
+            metric: MetricKind::Steps,
+            unit: Unit::Count,
+            target: 8_000,
+            comparator: Comparator::AtLeast,
+            cadence,
+            start_ts,
+            end_ts,
+        }
+    }
+
+    fn goal(cadence: Cadence, start_ts: i64, end_ts: i64) -> Goal {
+        Goal {
+            profile: Pubkey::default(),
+            id: 0,
+            metric: MetricKind::Steps,
+            unit: Unit::Count,
+            target: 8_000,
+            comparator: Comparator::AtLeast,
+            cadence,
+            start_ts,
+            end_ts,
+            status: GoalStatus::Active,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn goal_input_requires_target_and_future_window() {
+        goal_input(Cadence::Daily, 0, DAY).validate(0).unwrap();
+        let mut input = goal_input(Cadence::Daily, 0, DAY);
+        input.target = 0;
+        assert_err(input.validate(0), ErrorCode::InvalidGoalTarget);
+        assert_err(
+            goal_input(Cadence::Daily, 0, DAY).validate(DAY),
+            ErrorCode::InvalidGoalWindow,
+        );
+    }
+
+    #[test]
+    fn goal_input_rejects_windows_that_already_started() {
+        // Activity from the past week, settled right after creation.
+        assert_err(
+            goal_input(Cadence::Daily, 3 * DAY, 10 * DAY + 1).validate(10 * DAY),
+            ErrorCode::InvalidGoalWindow,
+        );
+        assert_err(
+            goal_input(Cadence::Daily, DAY - 1, 2 * DAY).validate(DAY),
+            ErrorCode::InvalidGoalWindow,
+        );
+    }
+
+    #[test]
+    fn goal_input_caps_window_length() {
+        goal_input(Cadence::Daily, 0, MAX_GOAL_DURATION)
+            .validate(0)
+            .unwrap();
+        assert_err(
+            goal_input(Cadence::Daily, 0, MAX_GOAL_DURATION + 1).validate(0),
+            ErrorCode::InvalidGoalWindow,
+        );
+        assert_err(
+            goal_input(Cadence::Daily, 0, i64::MAX).validate(0),
+            ErrorCode::InvalidGoalWindow,
+        );
+    }
+
+    #[test]
+    fn goal_input_window_covers_one_period() {
+        assert_err(
+            goal_input(Cadence::Daily, 0, DAY - 1).validate(0),
+            ErrorCode::InvalidGoalWindow,
+        );
+        assert_err(
+            goal_input(Cadence::Weekly, 0, 6 * DAY).validate(0),
+            ErrorCode::InvalidGoalWindow,
+        );
+        goal_input(Cadence::Weekly, 0, 7 * DAY).validate(0).unwrap();
+    }
+
+    #[test]
+    fn goal_period_count_rounds_partial_periods_up() {
+        assert_eq!(goal(Cadence::Daily, 0, 7 * DAY).period_count().unwrap(), 7);
+        assert_eq!(
+            goal(Cadence::Daily, 0, 7 * DAY + 1).period_count().unwrap(),
+            8
+        );
+        assert_eq!(goal(Cadence::Weekly, 0, 7 * DAY).period_count().unwrap(), 1);
+        assert_eq!(
+            goal(Cadence::Weekly, 0, 15 * DAY).period_count().unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn goal_period_count_errors_instead_of_overflowing() {
+        assert_err(
+            goal(Cadence::Daily, i64::MIN, i64::MAX).period_count(),
+            ErrorCode::MathOverflow,
+        );
+        assert_err(
+            goal(Cadence::Daily, 0, i64::MAX).period_count(),
+            ErrorCode::MathOverflow,
+        );
+        // More periods than fit in a u32.
+        assert_err(
+            goal(Cadence::Daily, 0, (u32::MAX as i64 + 1) * DAY).period_count(),
+            ErrorCode::MathOverflow,
+        );
+    }
+
+    #[test]
+    fn goal_settles_once_after_its_window() {
+        let mut g = goal(Cadence::Daily, 0, 7 * DAY);
+        assert_err(g.settle(7, 7 * DAY - 1), ErrorCode::GoalWindowOpen);
+        g.settle(7, 7 * DAY).unwrap();
+        assert!(g.status == GoalStatus::Completed);
+        assert_err(g.settle(7, 8 * DAY), ErrorCode::GoalNotActive);
+
+        let mut g = goal(Cadence::Daily, 0, 7 * DAY);
+        g.settle(6, 7 * DAY).unwrap();
+        assert!(g.status == GoalStatus::Failed);
+        assert_err(g.settle(7, 7 * DAY), ErrorCode::GoalNotActive);
+    }
+
+    fn scope(metrics: Vec<MetricKind>, record_indices: Vec<u64>) -> GrantScope {
+        GrantScope {
+            metrics,
+            record_indices,
+        }
+    }
+
+    #[test]
+    fn grant_scope_must_be_non_empty_and_bounded() {
+        scope(vec![MetricKind::Steps], vec![]).validate().unwrap();
+        scope(vec![], vec![4]).validate().unwrap();
+        assert_err(scope(vec![], vec![]).validate(), ErrorCode::EmptyGrantScope);
+        assert_err(
+            scope(vec![MetricKind::Steps; MAX_GRANT_METRICS + 1], vec![]).validate(),
+            ErrorCode::GrantScopeTooLarge,
+        );
+        assert_err(
+            scope(vec![], (0..=MAX_GRANT_RECORDS as u64).collect()).validate(),
+            ErrorCode::GrantScopeTooLarge,
+        );
+    }
+
+    #[test]
+    fn grant_mode_must_start_usable() {
+        GrantMode::Expiring.validate().unwrap();
+        GrantMode::LimitedReads { remaining: 1 }.validate().unwrap();
+        GrantMode::OneTimeExport { used: false }.validate().unwrap();
+        assert_err(
+            GrantMode::LimitedReads { remaining: 0 }.validate(),
+            ErrorCode::InvalidGrantMode,
+        );
+        assert_err(
+            GrantMode::OneTimeExport { used: true }.validate(),
+            ErrorCode::InvalidGrantMode,
+        );
+    }
+
+    fn grant(scope: GrantScope, mode: GrantMode) -> AccessGrant {
+        AccessGrant {
+            profile: Pubkey::default(),
+            grantee: Pubkey::new_unique(),
+            scope,
+            expires_at: DAY,
+            purpose: PurposeCode::Treatment,
+            mode,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn record(index: u64, metric: MetricKind) -> HealthRecord {
+        HealthRecord {
+            profile: Pubkey::default(),
+            index,
+            storage_uri: format!("ar://{ARWEAVE_ID}"),
+            content_hash: [0; 32],
+            metric,
+            unit: Unit::Count,
+            recorded_at: 0,
+            schema_version: 1,
+            created_at: 0,
+            superseded_by: None,
+            key_version: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn grant_covers_listed_metrics_and_indices() {
+        let g = grant(scope(vec![MetricKind::Steps], vec![7]), GrantMode::Expiring);
+        assert!(g.covers(&record(0, MetricKind::Steps)));
+        assert!(g.covers(&record(7, MetricKind::Glucose)));
+        assert!(!g.covers(&record(1, MetricKind::Glucose)));
+    }
+
+    #[test]
+    fn grant_mode_consume_exhausts_limited_modes() {
+        let mut reads = GrantMode::LimitedReads { remaining: 2 };
+        reads.consume();
+        assert!(!reads.is_exhausted());
+        reads.consume();
+        assert!(reads == GrantMode::LimitedReads { remaining: 0 });
+        assert!(reads.is_exhausted());
+
+        let mut export = GrantMode::OneTimeExport { used: false };
+        export.consume();
+        assert!(export.is_exhausted());
+
+        let mut expiring = GrantMode::Expiring;
+        expiring.consume();
+        assert!(!expiring.is_exhausted());
+    }
+
+    #[test]
+    fn grant_check_usable_enforces_expiry_then_uses() {
+        let g = grant(scope(vec![MetricKind::Steps], vec![]), GrantMode::Expiring);
+        g.check_usable(DAY - 1).unwrap();
+        assert_err(g.check_usable(DAY), ErrorCode::GrantExpired);
+
+        let g = grant(
+            scope(vec![MetricKind::Steps], vec![]),
+            GrantMode::OneTimeExport { used: true },
+        );
+        assert_err(g.check_usable(0), ErrorCode::GrantExhausted);
+    }
+
+    fn log_entry(record_index: u64) -> AccessLogEntry {
+        AccessLogEntry {
+            grantee: Pubkey::default(),
+            grant: Pubkey::default(),
+            accessed_at: 0,
+            record_index,
+            metric: 0,
+            purpose: 0,
+            _padding: [0; 6],
+        }
+    }
+
+    #[test]
+    fn access_log_wraps_around_overwriting_oldest() {
+        let mut log = AccessLog {
+            profile: Pubkey::default(),
+            total_entries: 0,
+            entries: [log_entry(u64::MAX); ACCESS_LOG_CAPACITY],
+        };
+        for i in 0..ACCESS_LOG_CAPACITY as u64 {
+            log.push(log_entry(i));
+        }
+        assert_eq!(log.total_entries, ACCESS_LOG_CAPACITY as u64);
+        assert_eq!(log.entries[ACCESS_LOG_CAPACITY - 1].record_index, 63);
+
+        log.push(log_entry(100));
+        log.push(log_entry(101));
+        assert_eq!(log.total_entries, ACCESS_LOG_CAPACITY as u64 + 2);
+        assert_eq!(log.entries[0].record_index, 100);
+        assert_eq!(log.entries[1].record_index, 101);
+        assert_eq!(log.entries[2].record_index, 2);
+    }
+
+    #[test]
+    fn utc_month_start_handles_leap_years_and_boundaries() {
+        assert_eq!(utc_month_start(0), 0);
+        // 2024-02-29 12:00 and 2024-03-01 00:00 UTC.
+        assert_eq!(utc_month_start(1_709_208_000), 1_706_745_600);
+        assert_eq!(utc_month_start(1_709_251_200), 1_709_251_200);
+        assert_eq!(utc_month_start(1_709_251_199), 1_706_745_600);
+        // 2023-12-31 23:59:59 UTC.
+        assert_eq!(utc_month_start(1_704_067_199), 1_701_388_800);
+        // 2000-03-15 UTC, across the 400-year leap rule.
+        assert_eq!(utc_month_start(951_868_800 + 14 * DAY), 951_868_800);
+        // Before the epoch.
+        assert_eq!(utc_month_start(-1), -2_678_400);
+    }
+
+    #[test]
+    fn ledger_credit_tracks_lifetime_and_month() {
+        let mut l = ledger();
+        let feb = 1_706_745_600;
+        let mar = 1_709_251_200;
+        l.credit(RewardReason::DailyLog, 10, feb + DAY).unwrap();
+        l.credit(RewardReason::GoalCompletion, 5, feb + 2 * DAY)
+            .unwrap();
+        assert_eq!(l.month_start, feb);
+        assert_eq!(l.earned_this_month, 15);
+
+        l.credit(RewardReason::DailyLog, 7, mar).unwrap();
+        assert_eq!(l.month_start, mar);
+        assert_eq!(l.earned_this_month, 7);
+        assert_eq!(l.earned_by_reason[RewardReason::DailyLog as usize], 17);
+        assert_eq!(l.earned_by_reason[RewardReason::GoalCompletion as usize], 5);
+
+        l.earned_by_reason[RewardReason::Referral as usize] = u64::MAX;
+        assert_err(
+            l.credit(RewardReason::Referral, 1, mar),
+            ErrorCode::MathOverflow,
+        );
+    }
 }
@@ -0,0 +1,222 @@
+const { assert } = require("chai");
+const {
+  addRecord,
+  expectError,
+  grantAccess,
+  grantPda,
+  newProfile,
+  newUser,
+  now,
+  pda,
+  program,
+  waitUntil,
+  web3,
+} = require("./helpers");
+
+describe("access grants", () => {
+  const revoke = (user, profile, accessGrant) =>
+    program.methods
+      .revokeAccess()
+      .accountsPartial({ userProfile: profile, accessGrant, authority: user.publicKey })
+      .signers([user])
+      .rpc();
+
+  it("records the grant and counts it as a profile child", async () => {
+    const { user, profile } = await newProfile();
+    const grantee = web3.Keypair.generate().publicKey;
+    const expiresAt = now() + 600;
+    const address = await grantAccess(user, grantee, {
+      scope: { metrics: [{ heartRate: {} }], recordIndices: [0, 3] },
+      expiresAt,
+      purpose: { research: {} },
+      mode: { limitedReads: { remaining: 2 } },
+    });
+
+    assert.ok(address.equals(grantPda(profile, grantee)));
+    const grant = await program.account.accessGrant.fetch(address);
+    assert.ok(grant.profile.equals(profile));
+    assert.ok(grant.grantee.equals(grantee));
+    assert.deepEqual(grant.scope.metrics, [{ heartRate: {} }]);
+    assert.deepEqual(grant.scope.recordIndices.map((i) => i.toNumber()), [0, 3]);
+    assert.equal(grant.expiresAt.toNumber(), expiresAt);
+    assert.deepEqual(grant.purpose, { research: {} });
+    assert.deepEqual(grant.mode, { limitedReads: { remaining: 2 } });
+    assert.equal((await program.account.userProfile.fetch(profile)).childCount, 1);
+  });
+
+  it("rejects unusable grants", async () => {
+    const { user } = await newProfile();
+    const grantee = web3.Keypair.generate().publicKey;
+    await expectError(
+      grantAccess(user, grantee, { scope: { metrics: [], recordIndices: [] } }),
+      "EmptyGrantScope"
+    );
+    await expectError(
+      grantAccess(user, grantee, {
+        scope: { metrics: [], recordIndices: Array.from({ length: 17 }, (_, i) => i) },
+      }),
+      "GrantScopeTooLarge"
+    );
+    await expectError(grantAccess(user, grantee, { expiresAt: now() - 1 }), "InvalidGrantExpiry");
+    await expectError(
+      grantAccess(user, grantee, { mode: { limitedReads: { remaining: 0 } } }),
+      "InvalidGrantMode"
+    );
+    await expectError(
+      grantAccess(user, grantee, { mode: { oneTimeExport: { used: true } } }),
+      "InvalidGrantMode"
+    );
+  });
+
+  it("revokes by closing the grant", async () => {
+    const { user, profile } = await newProfile();
+    const grantee = web3.Keypair.generate().publicKey;
+    const accessGrant = await grantAccess(user, grantee);
+
+    await revoke(user, profile, accessGrant);
+    assert.isNull(await program.account.accessGrant.fetchNullable(accessGrant));
+    assert.equal((await program.account.userProfile.fetch(profile)).childCount, 0);
+
+    // The same grantee can be granted access again afterwards.
+    await grantAccess(user, grantee);
+  });
+
+  it("only lets the profile owner revoke", async () => {
+    const { user, profile } = await newProfile();
+    const other = await newProfile();
+    const accessGrant = await grantAccess(user, web3.Keypair.generate().publicKey);
+    await expectError(revoke(other.user, profile, accessGrant), "ConstraintSeeds");
+  });
+});
+
+describe("record_access", () => {
+  const initLog = (user, profile) =>
+    program.methods
+      .initializeAccessLog()
+      .accountsPartial({ userProfile: profile, authority: user.publicKey })
+      .signers([user])
+      .rpc();
+
+  const access = (profile, accessGrant, healthRecord, grantee) =>
+    program.methods
+      .recordAccess()
+      .accountsPartial({
+        userProfile: profile,
+        accessGrant,
+        healthRecord,
+        accessLog: pda(Buffer.from("access_log"), profile.toBuffer()),
+        grantee: grantee.publicKey,
+      })
+      .signers([grantee])
+      .rpc();
+
+  const closeExpired = (user, profile, accessGrant) =>
+    program.methods
+      .closeExpiredGrant()
+      .accountsPartial({ userProfile: profile, accessGrant, owner: user.publicKey })
+      .rpc();
+
+  // A profile with one step record and a grantee. The access log is left
+  // for the first `record_access` to create.
+  const setup = async () => {
+    const { user, profile } = await newProfile();
+    const healthRecord = await addRecord(user);
+    const grantee = await newUser();
+    return { user, profile, healthRecord, grantee };
+  };
+
+  it("consumes limited reads and frees the grant once exhausted", async () => {
+    const { user, profile, healthRecord, grantee } = await setup();
+    const accessGrant = await grantAccess(user, grantee.publicKey, {
+      mode: { limitedReads: { remaining: 2 } },
+    });
+
+    await access(profile, accessGrant, healthRecord, grantee);
+    await expectError(closeExpired(user, profile, accessGrant), "GrantStillActive");
+    await access(profile, accessGrant, healthRecord, grantee);
+    const grant = await program.account.accessGrant.fetch(accessGrant);
+    assert.deepEqual(grant.mode, { limitedReads: { remaining: 0 } });
+    await expectError(access(profile, accessGrant, healthRecord, grantee), "GrantExhausted");
+
+    // Anyone may close it; the rent goes back to the profile owner.
+    await closeExpired(user, profile, accessGrant);
+    assert.isNull(await program.account.accessGrant.fetchNullable(accessGrant));
+    // The access log and the record remain.
+    assert.equal((await program.account.userProfile.fetch(profile)).childCount, 2);
+  });
+
+  it("creates the access log on first access at the grantee's expense", async () => {
+    const { user, profile, healthRecord, grantee } = await setup();
+    const accessLog = pda(Buffer.from("access_log"), profile.toBuffer());
+    const accessGrant = await grantAccess(user, grantee.publicKey);
+    assert.isNull(await program.account.accessLog.fetchNullable(accessLog));
+
+    const lamportsBefore = await program.provider.connection.getBalance(grantee.publicKey);
+    await access(profile, accessGrant, healthRecord, grantee);
+    const rent = await program.provider.connection.getBalance(accessLog);
+    const lamportsAfter = await program.provider.connection.getBalance(grantee.publicKey);
+    assert.isAtLeast(lamportsBefore - lamportsAfter, rent);
+
+    const log = await program.account.accessLog.fetch(accessLog);
+    assert.ok(log.profile.equals(profile));
+    assert.equal(log.totalEntries.toNumber(), 1);
+    // The record, the grant and the new log.
+    assert.equal((await program.account.userProfile.fetch(profile)).childCount, 3);
+  });
+
+  it("appends every access to a log the owner created", async () => {
+    const { user, profile, healthRecord, grantee } = await setup();
+    await initLog(user, profile);
+    const accessGrant = await grantAccess(user, grantee.publicKey, {
+      purpose: { coaching: {} },
+    });
+    await access(profile, accessGrant, healthRecord, grantee);
+    await access(profile, accessGrant, healthRecord, grantee);
+
+    const log = await program.account.accessLog.fetch(
+      pda(Buffer.from("access_log"), profile.toBuffer())
+    );
+    assert.ok(log.profile.equals(profile));
+    assert.equal(log.totalEntries.toNumber(), 2);
+    for (const entry of log.entries.slice(0, 2)) {
+      assert.ok(entry.grantee.equals(grantee.publicKey));
+      assert.ok(entry.grant.equals(accessGrant));
+      assert.equal(entry.recordIndex.toNumber(), 0);
+      // `PurposeCode::Coaching` and `MetricKind::Steps` discriminants.
+      assert.equal(entry.purpose, 2);
+      assert.equal(entry.metric, 0);
+    }
+    assert.equal(log.entries[2].accessedAt.toNumber(), 0);
+  });
+
+  it("allows a one-time export once", async () => {
+    const { user, profile, healthRecord, grantee } = await setup();
+    const accessGrant = await grantAccess(user, grantee.publicKey, {
+      mode: { oneTimeExport: { used: false } },
+    });
+    await access(profile, accessGrant, healthRecord, grantee);
+    await expectError(access(profile, accessGrant, healthRecord, grantee), "GrantExhausted");
+  });
+
+  it("only reaches records in the grant's scope", async () => {
+    const { user, profile, healthRecord, grantee } = await setup();
+    const accessGrant = await grantAccess(user, grantee.publicKey, {
+      scope: { metrics: [{ glucose: {} }], recordIndices: [] },
+    });
+    await expectError(
+      access(profile, accessGrant, healthRecord, grantee),
+      "RecordNotInGrantScope"
+    );
+  });
+
+  it("stops at expiry, after which the grant can be closed", async () => {
+    const { user, profile, healthRecord, grantee } = await setup();
+    const expiresAt = now() + 5;
+    const accessGrant = await grantAccess(user, grantee.publicKey, { expiresAt });
+    await waitUntil(expiresAt);
+
+    await expectError(access(profile, accessGrant, healthRecord, grantee), "GrantExpired");
+    await closeExpired(user, profile, accessGrant);
+    assert.isNull(await program.account.accessGrant.fetchNullable(accessGrant));
+  });
+});
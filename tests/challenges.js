@@ -0,0 +1,157 @@
+const { assert } = require("chai");
+const {
+  BN,
+  admin,
+  balance,
+  createProtocolChallenge,
+  ensureProtocol,
+  expectError,
+  mintToOwner,
+  newUser,
+  pda,
+  program,
+  web3,
+} = require("./helpers");
+
+const sleep = (ms) => new Promise((resolve) => setTimeout(resolve, ms));
+
+// Settlement needs the result window (three days) to pass, and the
+// unclaimed-prize sweep the claim window (thirty more), neither of which a
+// live validator can fast-forward; the payout arithmetic and deadlines are
+// covered by the unit tests on `Challenge`. These tests cover everything up
+// to settlement and the guards on the paths after it.
+describe("challenges", () => {
+  let protocol;
+  before(async () => {
+    protocol = await ensureProtocol();
+  });
+
+  const createChallenge = (options) => createProtocolChallenge(protocol, options);
+
+  const join = async ({ challenge, challengeVault }, participant) => {
+    const { mint, tokenProgram } = protocol;
+    const participantTokenAccount = await mintToOwner(
+      mint,
+      participant.publicKey,
+      100,
+      tokenProgram
+    );
+    return program.methods
+      .joinChallenge()
+      .accountsPartial({
+        challenge,
+        challengeEntry: entryOf(challenge, participant),
+        mint,
+        challengeVault,
+        participant: participant.publicKey,
+        participantTokenAccount,
+        tokenProgram,
+      })
+      .signers([participant])
+      .rpc();
+  };
+
+  const entryOf = (challenge, participant) =>
+    pda(Buffer.from("challenge_entry"), challenge.toBuffer(), participant.publicKey.toBuffer());
+
+  it("escrows entry fees until the challenge is full", async () => {
+    const c = await createChallenge();
+    const [alice, bob, carol] = await Promise.all([newUser(), newUser(), newUser()]);
+
+    await join(c, alice);
+    await join(c, bob);
+    await expectError(join(c, carol), "ChallengeFull");
+
+    const account = await program.account.challenge.fetch(c.challenge);
+    assert.equal(account.participantCount, 2);
+    assert.equal(account.pot.toString(), "200");
+    assert.equal(await balance(c.challengeVault, protocol.tokenProgram), "200");
+    const entry = await program.account.challengeEntry.fetch(entryOf(c.challenge, alice));
+    assert.isTrue(entry.participant.equals(alice.publicKey));
+    assert.isFalse(entry.reachedTarget);
+  });
+
+  it("rejects joining after the start", async () => {
+    const c = await createChallenge({ startIn: 2 });
+    await sleep(3_000);
+    await expectError(join(c, await newUser()), "ChallengeAlreadyStarted");
+  });
+
+  it("rejects results before the challenge ends", async () => {
+    const c = await createChallenge();
+    const alice = await newUser();
+    await join(c, alice);
+    const account = await program.account.challenge.fetch(c.challenge);
+
+    await expectError(
+      program.methods
+        .recordChallengeResult({
+          challenge: c.challenge,
+          participant: alice.publicKey,
+          metric: account.metric,
+          unit: account.unit,
+          startTs: account.startTs,
+          endTs: account.endTs,
+          value: new BN(20_000),
+        })
+        .accountsPartial({
+          challenge: c.challenge,
+          challengeEntry: entryOf(c.challenge, alice),
+          instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .rpc(),
+      "ChallengeNotEnded"
+    );
+  });
+
+  it("keeps every post-settlement path closed while the challenge is open", async () => {
+    const { mint, tokenProgram } = protocol;
+    const c = await createChallenge();
+    const alice = await newUser();
+    await join(c, alice);
+
+    await expectError(
+      program.methods.settleChallenge().accountsPartial({ challenge: c.challenge }).rpc(),
+      "ChallengeResultWindowOpen"
+    );
+    await expectError(
+      program.methods
+        .claimChallengePrize()
+        .accountsPartial({
+          challenge: c.challenge,
+          challengeEntry: entryOf(c.challenge, alice),
+          mint,
+          challengeVault: c.challengeVault,
+          participant: alice.publicKey,
+          tokenProgram,
+        })
+        .signers([alice])
+        .rpc(),
+      "ChallengeNotSettled"
+    );
+    await expectError(
+      program.methods
+        .closeChallengeEntry()
+        .accountsPartial({
+          challenge: c.challenge,
+          challengeEntry: entryOf(c.challenge, alice),
+          participant: alice.publicKey,
+        })
+        .rpc(),
+      "ChallengeNotSettled"
+    );
+    await expectError(
+      program.methods
+        .closeChallenge()
+        .accountsPartial({
+          challenge: c.challenge,
+          mint,
+          challengeVault: c.challengeVault,
+          creator: admin.publicKey,
+          tokenProgram,
+        })
+        .rpc(),
+      "ChallengeNotSettled"
+    );
+  });
+});
@@ -0,0 +1,209 @@
+const { assert } = require("chai");
+const { getAssociatedTokenAddressSync } = require("@solana/spl-token");
+const {
+  BN,
+  DAY,
+  admin,
+  createGoal,
+  ensureProtocol,
+  expectError,
+  fundVault,
+  goalPda,
+  newProfile,
+  now,
+  program,
+  waitUntil,
+  web3,
+} = require("./helpers");
+
+const GOAL_ATTESTATION_DOMAIN = Buffer.from("healthkey:goal:v1");
+
+const goalUpdate = (update = {}) => ({
+  target: null,
+  comparator: null,
+  cadence: null,
+  startTs: null,
+  endTs: null,
+  ...update,
+});
+
+describe("goals", () => {
+  const update = (user, profile, goal, changes) =>
+    program.methods
+      .updateGoal(goalUpdate(changes))
+      .accountsPartial({ userProfile: profile, goal, authority: user.publicKey })
+      .signers([user])
+      .rpc();
+
+  const abandon = (user, profile, goal) =>
+    program.methods
+      .abandonGoal()
+      .accountsPartial({ userProfile: profile, goal, authority: user.publicKey })
+      .signers([user])
+      .rpc();
+
+  it("creates goals with consecutive ids", async () => {
+    const { user, profile } = await newProfile();
+    const first = await createGoal(user, profile);
+    const second = await createGoal(user, profile, { cadence: { weekly: {} } });
+
+    assert.ok(first.equals(goalPda(profile, 0)));
+    const goal = await program.account.goal.fetch(second);
+    assert.equal(goal.id.toNumber(), 1);
+    assert.deepEqual(goal.cadence, { weekly: {} });
+    assert.deepEqual(goal.status, { active: {} });
+
+    const account = await program.account.userProfile.fetch(profile);
+    assert.equal(account.goalCount.toNumber(), 2);
+    assert.equal(account.childCount, 2);
+  });
+
+  it("rejects invalid goal terms", async () => {
+    const { user, profile } = await newProfile();
+    await expectError(createGoal(user, profile, { target: new BN(0) }), "InvalidGoalTarget");
+    await expectError(createGoal(user, profile, { unit: { minutes: {} } }), "InvalidUnitForMetric");
+    await expectError(
+      createGoal(user, profile, { startTs: new BN(now() - 3 * DAY), endTs: new BN(now() - DAY) }),
+      "InvalidGoalWindow"
+    );
+    // Activity that already happened cannot be turned into a goal.
+    await expectError(
+      createGoal(user, profile, { startTs: new BN(now() - 7 * DAY), endTs: new BN(now() + 60) }),
+      "InvalidGoalWindow"
+    );
+    await expectError(
+      createGoal(user, profile, { endTs: new BN(now() + 400 * DAY) }),
+      "InvalidGoalWindow"
+    );
+    // A weekly goal needs at least a week-long window.
+    await expectError(
+      createGoal(user, profile, { cadence: { weekly: {} }, endTs: new BN(now() + 3 * DAY) }),
+      "InvalidGoalWindow"
+    );
+  });
+
+  it("lets the terms change until the window opens", async () => {
+    const { user, profile } = await newProfile();
+    const pending = await createGoal(user, profile);
+    await update(user, profile, pending, { target: new BN(10_000), comparator: { atMost: {} } });
+    const goal = await program.account.goal.fetch(pending);
+    assert.equal(goal.target.toNumber(), 10_000);
+    assert.deepEqual(goal.comparator, { atMost: {} });
+    await expectError(update(user, profile, pending, { target: new BN(0) }), "InvalidGoalTarget");
+
+    const startTs = now() + 5;
+    const started = await createGoal(user, profile, {
+      startTs: new BN(startTs),
+      endTs: new BN(startTs + DAY),
+    });
+    await waitUntil(startTs);
+    await expectError(update(user, profile, started, { target: new BN(1) }), "GoalAlreadyStarted");
+  });
+
+  it("abandons an active goal once", async () => {
+    const { user, profile } = await newProfile();
+    const goal = await createGoal(user, profile);
+    await abandon(user, profile, goal);
+    assert.deepEqual((await program.account.goal.fetch(goal)).status, { abandoned: {} });
+
+    await expectError(abandon(user, profile, goal), "GoalNotActive");
+    await expectError(update(user, profile, goal, { target: new BN(1) }), "GoalNotActive");
+  });
+});
+
+describe("settle_goal", () => {
+  const oracle = web3.Keypair.generate();
+  let protocol;
+  let vaultTokenAccount;
+
+  before(async () => {
+    protocol = await ensureProtocol();
+    vaultTokenAccount = await fundVault(protocol, 1_000);
+    await program.methods.addOracle(oracle.publicKey).accounts({ admin: admin.publicKey }).rpc();
+  });
+
+  after(() =>
+    program.methods.removeOracle(oracle.publicKey).accounts({ admin: admin.publicKey }).rpc()
+  );
+
+  const userAta = (user) =>
+    getAssociatedTokenAddressSync(protocol.mint, user.publicKey, false, protocol.tokenProgram);
+
+  const attestationFor = async (user, goal, periodsMet) => {
+    const terms = await program.account.goal.fetch(goal);
+    return {
+      goal,
+      user: user.publicKey,
+      metric: terms.metric,
+      unit: terms.unit,
+      target: terms.target,
+      comparator: terms.comparator,
+      cadence: terms.cadence,
+      startTs: terms.startTs,
+      endTs: terms.endTs,
+      periodsMet,
+    };
+  };
+
+  const settle = (user, profile, goal, attestation) => {
+    const message = Buffer.concat([
+      GOAL_ATTESTATION_DOMAIN,
+      program.coder.types.encode("goalAttestation", attestation),
+    ]);
+    return program.methods
+      .settleGoal(attestation)
+      .accountsPartial({
+        userProfile: profile,
+        goal,
+        mint: protocol.mint,
+        authority: user.publicKey,
+        userTokenAccount: userAta(user),
+        vaultTokenAccount,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenProgram: protocol.tokenProgram,
+      })
+      .preInstructions([
+        web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: oracle.secretKey,
+          message,
+        }),
+      ])
+      .signers([user])
+      .rpc();
+  };
+
+  const closeGoal = (user, profile, goal) =>
+    program.methods
+      .closeGoal()
+      .accountsPartial({ userProfile: profile, goal, authority: user.publicKey })
+      .signers([user])
+      .rpc();
+
+  // A goal window lasts at least a day, so settling a finished goal is
+  // covered by the `goal_settles_once_after_its_window` unit test.
+  it("waits for the goal window to close", async () => {
+    const { user, profile } = await newProfile();
+    const goal = await createGoal(user, profile);
+    const attestation = await attestationFor(user, goal, 7);
+    await expectError(
+      settle(user, profile, goal, { ...attestation, target: attestation.target.addn(1) }),
+      "AttestationMismatch"
+    );
+    await expectError(settle(user, profile, goal, attestation), "GoalWindowOpen");
+    await expectError(closeGoal(user, profile, goal), "GoalStillActive");
+  });
+
+  it("closes a goal once it is no longer active", async () => {
+    const { user, profile } = await newProfile();
+    const goal = await createGoal(user, profile);
+    await program.methods
+      .abandonGoal()
+      .accountsPartial({ userProfile: profile, goal, authority: user.publicKey })
+      .signers([user])
+      .rpc();
+
+    await closeGoal(user, profile, goal);
+    assert.isNull(await program.account.goal.fetchNullable(goal));
+    assert.equal((await program.account.userProfile.fetch(profile)).childCount, 0);
+  });
+});
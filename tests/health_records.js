@@ -0,0 +1,104 @@
+const { assert } = require("chai");
+const {
+  ARWEAVE_ID,
+  BN,
+  IPFS_CID,
+  addRecord,
+  cpiEvents,
+  expectError,
+  newProfile,
+  now,
+  program,
+  recordInput,
+  recordPda,
+} = require("./helpers");
+
+describe("health records", () => {
+  it("stores records at consecutive indices", async () => {
+    const { user, profile } = await newProfile();
+    const first = await addRecord(user);
+    const second = await addRecord(user, { metric: { workout: {} }, unit: { kilocalories: {} } });
+
+    assert.ok(first.equals(recordPda(profile, 0)));
+    assert.ok(second.equals(recordPda(profile, 1)));
+    const record = await program.account.healthRecord.fetch(second);
+    assert.ok(record.profile.equals(profile));
+    assert.equal(record.index.toNumber(), 1);
+    assert.deepEqual(record.unit, { kilocalories: {} });
+    assert.isNull(record.supersededBy);
+
+    const account = await program.account.userProfile.fetch(profile);
+    assert.equal(account.recordCount.toNumber(), 2);
+    assert.equal(account.childCount, 2);
+  });
+
+  it("validates the record before anchoring it", async () => {
+    const { user } = await newProfile();
+    await expectError(addRecord(user, { storageUri: "https://example.com" }), "InvalidStorageUri");
+    await expectError(addRecord(user, { unit: { grams: {} } }), "InvalidUnitForMetric");
+    await expectError(
+      addRecord(user, { recordedAt: new BN(now() + 3_600) }),
+      "RecordedInFuture"
+    );
+  });
+
+  it("supersedes a record exactly once", async () => {
+    const { user, profile } = await newProfile();
+    const previousRecord = await addRecord(user);
+    const supersede = (healthRecord) =>
+      program.methods
+        .supersedeHealthRecord(recordInput({ contentHash: Array(32).fill(9) }))
+        .accountsPartial({
+          userProfile: profile,
+          previousRecord,
+          healthRecord,
+          authority: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+
+    await supersede(recordPda(profile, 1));
+    const previous = await program.account.healthRecord.fetch(previousRecord);
+    assert.equal(previous.supersededBy.toNumber(), 1);
+    const replacement = await program.account.healthRecord.fetch(recordPda(profile, 1));
+    assert.deepEqual(replacement.contentHash, Array(32).fill(9));
+
+    await expectError(supersede(recordPda(profile, 2)), "RecordAlreadySuperseded");
+  });
+
+  it("puts the storage pointer in the record events", async () => {
+    const { user, profile } = await newProfile();
+    const input = recordInput({ storageUri: `ipfs://${IPFS_CID}` });
+    const signature = await program.methods
+      .addHealthRecord(input)
+      .accountsPartial({
+        userProfile: profile,
+        healthRecord: recordPda(profile, 0),
+        authority: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+
+    const [{ data: added }] = (await cpiEvents(signature)).filter(
+      (e) => e.name === "healthRecordAdded"
+    );
+    assert.equal(added.storageUri, `ipfs://${IPFS_CID}`);
+    assert.deepEqual(added.contentHash, input.contentHash);
+
+    const rotated = await program.methods
+      .rotateRecordKey(`ar://${ARWEAVE_ID}`, Array(32).fill(4))
+      .accountsPartial({
+        userProfile: profile,
+        healthRecord: recordPda(profile, 0),
+        authority: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    const [{ data: rotation }] = (await cpiEvents(rotated)).filter(
+      (e) => e.name === "recordKeyRotated"
+    );
+    assert.equal(rotation.storageUri, `ar://${ARWEAVE_ID}`);
+    assert.deepEqual(rotation.contentHash, Array(32).fill(4));
+    assert.equal(rotation.keyVersion, 1);
+  });
+});
@@ -1,13 +1,34 @@
-const anchor = require("@coral-xyz/anchor");
+const { assert } = require("chai");
+const {
+  DEFAULT_LIMITS,
+  configPda,
+  createTestMint,
+  ensureProtocol,
+  expectError,
+  newUser,
+  program,
+  programData,
+} = require("./helpers");
 
-describe("healthkey_protocol", () => {
-  // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+describe("initialize_protocol", () => {
+  it("rejects a signer that is not the upgrade authority", async function () {
+    if (await program.account.protocolConfig.fetchNullable(configPda)) this.skip();
 
-  it("Is initialized!", async () => {
-    // Add your test here.
-    const program = anchor.workspace.healthkeyProtocol;
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+    const intruder = await newUser();
+    const mint = await createTestMint();
+    await expectError(
+      program.methods
+        .initializeProtocol([], DEFAULT_LIMITS)
+        .accountsPartial({ mint, admin: intruder.publicKey, programData })
+        .signers([intruder])
+        .rpc(),
+      "Unauthorized"
+    );
+  });
+
+  it("lets the upgrade authority create the config", async () => {
+    const { mint } = await ensureProtocol();
+    const config = await program.account.protocolConfig.fetch(configPda);
+    assert.isTrue(config.mint.equals(mint));
   });
 });
@@ -0,0 +1,457 @@
+const anchor = require("@coral-xyz/anchor");
+const { assert } = require("chai");
+const {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} = require("@solana/spl-token");
+
+const { BN, web3 } = anchor;
+
+// Shared fixtures for the mocha suites. Every suite runs against the same
+// validator, so the protocol config is created once and reused.
+
+const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+const program = anchor.workspace.healthkeyProtocol;
+const admin = provider.wallet;
+
+const DAY = 86_400;
+
+const DEFAULT_LIMITS = {
+  epochDuration: new BN(DAY),
+  userEpochCap: new BN(1_000_000_000_000),
+  globalEpochCap: new BN("1000000000000000"),
+  claimHorizon: new BN(7 * DAY),
+};
+
+const pda = (...seeds) =>
+  web3.PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+const u64 = (n) => new BN(n).toArrayLike(Buffer, "le", 8);
+const i64 = (n) => new BN(n).toTwos(64).toArrayLike(Buffer, "le", 8);
+
+const configPda = pda(Buffer.from("config"));
+const programData = web3.PublicKey.findProgramAddressSync(
+  [program.programId.toBuffer()],
+  new web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+)[0];
+const vaultPda = pda(Buffer.from("vault"));
+
+const now = () => Math.floor(Date.now() / 1000);
+const dayStart = (ts = now()) => ts - (ts % DAY);
+
+const airdrop = async (pubkey, sol = 1) => {
+  const sig = await provider.connection.requestAirdrop(pubkey, sol * web3.LAMPORTS_PER_SOL);
+  await provider.connection.confirmTransaction(sig, "confirmed");
+};
+
+const newUser = async () => {
+  const user = web3.Keypair.generate();
+  await airdrop(user.publicKey);
+  return user;
+};
+
+// Assert that `promise` rejects with the given Anchor error code name.
+const expectError = async (promise, code) => {
+  try {
+    await promise;
+  } catch (err) {
+    const actual = err.error?.errorCode?.code ?? err.toString();
+    assert.include(actual, code);
+    return;
+  }
+  assert.fail(`expected ${code}`);
+};
+
+const createTestMint = (tokenProgram = TOKEN_PROGRAM_ID, decimals = 6) =>
+  createMint(
+    provider.connection,
+    admin.payer,
+    admin.publicKey,
+    null,
+    decimals,
+    undefined,
+    undefined,
+    tokenProgram
+  );
+
+// Mint `amount` to `owner`'s ATA (created if needed) and return its address.
+const mintToOwner = async (mint, owner, amount, tokenProgram = TOKEN_PROGRAM_ID) => {
+  const ata = await getOrCreateAssociatedTokenAccount(
+    provider.connection,
+    admin.payer,
+    mint,
+    owner,
+    true,
+    undefined,
+    undefined,
+    tokenProgram
+  );
+  await mintTo(
+    provider.connection,
+    admin.payer,
+    mint,
+    ata.address,
+    admin.publicKey,
+    amount,
+    [],
+    undefined,
+    tokenProgram
+  );
+  return ata.address;
+};
+
+const balance = async (address, tokenProgram = TOKEN_PROGRAM_ID) =>
+  (await getAccount(provider.connection, address, undefined, tokenProgram)).amount.toString();
+
+// Token program owning the protocol mint. Run the whole suite a second time
+// with `HEALTH_TOKEN_PROGRAM=token-2022 anchor test` to cover Token-2022.
+const PROTOCOL_TOKEN_PROGRAM =
+  process.env.HEALTH_TOKEN_PROGRAM === "token-2022" ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
+
+// Create the protocol config on first use and return its mint and token
+// program. Suites may run in any order.
+let protocol;
+const ensureProtocol = async () => {
+  if (protocol) return protocol;
+
+  const existing = await program.account.protocolConfig.fetchNullable(configPda);
+  if (existing) {
+    const info = await provider.connection.getAccountInfo(existing.mint);
+    protocol = { mint: existing.mint, tokenProgram: info.owner };
+    return protocol;
+  }
+
+  const tokenProgram = PROTOCOL_TOKEN_PROGRAM;
+  const mint = await createTestMint(tokenProgram);
+  await program.methods
+    .initializeProtocol([], DEFAULT_LIMITS)
+    .accountsPartial({ mint, admin: admin.publicKey, programData })
+    .rpc();
+  protocol = { mint, tokenProgram };
+  return protocol;
+};
+
+// A single protocol issuer shared by every suite; the config holds at most
+// MAX_REWARD_ISSUERS keys.
+const sharedIssuer = web3.Keypair.generate();
+let issuerRegistered = false;
+const protocolIssuer = async () => {
+  if (!issuerRegistered) {
+    await program.methods
+      .addRewardIssuer(sharedIssuer.publicKey)
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    issuerRegistered = true;
+  }
+  return sharedIssuer;
+};
+
+// Apply `limits` over the defaults. The claim horizon can only shrink, so
+// the current one is kept unless the caller overrides it.
+const setLimits = async (limits) => {
+  const { claimHorizon } = (await program.account.protocolConfig.fetch(configPda)).limits;
+  return program.methods
+    .updateRewardLimits({ ...DEFAULT_LIMITS, claimHorizon, ...limits })
+    .accounts({ admin: admin.publicKey })
+    .rpc();
+};
+
+// Decode the events a confirmed transaction emitted through `emit_cpi!`:
+// self-CPIs whose data is the event tag, the event discriminator and the
+// Borsh-encoded event.
+const cpiEvents = async (signature) => {
+  const tx = await provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const keys = tx.transaction.message.staticAccountKeys;
+  return tx.meta.innerInstructions
+    .flatMap(({ instructions }) => instructions)
+    .filter((ix) => keys[ix.programIdIndex].equals(program.programId))
+    .map((ix) => {
+      const data = anchor.utils.bytes.bs58.decode(ix.data);
+      return program.coder.events.decode(anchor.utils.bytes.base64.encode(data.subarray(8)));
+    })
+    .filter(Boolean);
+};
+
+// Well-formed storage pointers for profiles and records.
+const ARWEAVE_ID = "A".repeat(21) + "-_" + "z".repeat(20);
+const IPFS_CID = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+const profilePda = (authority) => pda(Buffer.from("user_profile"), authority.toBuffer());
+
+// Create a fresh funded user with a profile.
+const newProfile = async () => {
+  const user = await newUser();
+  await program.methods
+    .initializeUserProfile(ARWEAVE_ID, { arweave: {} })
+    .accounts({ authority: user.publicKey })
+    .signers([user])
+    .rpc();
+  return { user, profile: profilePda(user.publicKey) };
+};
+
+const recordPda = (profile, index) =>
+  pda(Buffer.from("health_record"), profile.toBuffer(), u64(index));
+
+// A valid step-count record; override any field through `input`.
+const recordInput = (input = {}) => ({
+  storageUri: `ar://${ARWEAVE_ID}`,
+  contentHash: Array(32).fill(7),
+  metric: { steps: {} },
+  unit: { count: {} },
+  recordedAt: new BN(now() - 60),
+  schemaVersion: 1,
+  ...input,
+});
+
+// Add a record at the profile's next index and return its address.
+const addRecord = async (user, input = {}) => {
+  const profile = profilePda(user.publicKey);
+  const { recordCount } = await program.account.userProfile.fetch(profile);
+  const healthRecord = recordPda(profile, recordCount);
+  await program.methods
+    .addHealthRecord(recordInput(input))
+    .accountsPartial({ userProfile: profile, healthRecord, authority: user.publicKey })
+    .signers([user])
+    .rpc();
+  return healthRecord;
+};
+
+const goalPda = (profile, id) => pda(Buffer.from("goal"), profile.toBuffer(), u64(id));
+
+// 8,000 steps a day over a window that opens tomorrow; override any field.
+const goalInput = (input = {}) => {
+  const start = now() + DAY;
+  return {
+    metric: { steps: {} },
+    unit: { count: {} },
+    target: new BN(8_000),
+    comparator: { atLeast: {} },
+    cadence: { daily: {} },
+    startTs: new BN(start),
+    endTs: new BN(start + 7 * DAY),
+    ...input,
+  };
+};
+
+const createGoal = async (user, profile, input = {}) => {
+  const { goalCount } = await program.account.userProfile.fetch(profile);
+  const goal = goalPda(profile, goalCount);
+  await program.methods
+    .createGoal(goalInput(input))
+    .accountsPartial({ userProfile: profile, goal, authority: user.publicKey })
+    .signers([user])
+    .rpc();
+  return goal;
+};
+
+const grantPda = (profile, grantee) =>
+  pda(Buffer.from("access_grant"), profile.toBuffer(), grantee.toBuffer());
+
+// Grant `grantee` access to every step record for an hour; override any
+// argument through `options`.
+const grantAccess = async (
+  user,
+  grantee,
+  {
+    scope = { metrics: [{ steps: {} }], recordIndices: [] },
+    expiresAt = now() + 3_600,
+    purpose = { treatment: {} },
+    mode = { expiring: {} },
+  } = {}
+) => {
+  const profile = profilePda(user.publicKey);
+  const accessGrant = grantPda(profile, grantee);
+  await program.methods
+    .grantAccess(grantee, scope, new BN(expiresAt), purpose, mode)
+    .accountsPartial({ userProfile: profile, accessGrant, authority: user.publicKey })
+    .signers([user])
+    .rpc();
+  return accessGrant;
+};
+
+// Resolve once the cluster clock has passed `ts`.
+const waitUntil = async (ts) => {
+  for (;;) {
+    const slot = await provider.connection.getSlot("confirmed");
+    if ((await provider.connection.getBlockTime(slot)) > ts) return;
+    await new Promise((resolve) => setTimeout(resolve, 500));
+  }
+};
+
+// Deposit `amount` of the protocol mint into the vault and return the vault
+// token account.
+const fundVault = async ({ mint, tokenProgram }, amount) => {
+  const vaultTokenAccount = getAssociatedTokenAddressSync(mint, vaultPda, true, tokenProgram);
+  const funderTokenAccount = await mintToOwner(mint, admin.publicKey, amount, tokenProgram);
+  await program.methods
+    .fundVault(new BN(amount))
+    .accountsPartial({
+      mint,
+      funder: admin.publicKey,
+      funderTokenAccount,
+      vaultTokenAccount,
+      tokenProgram,
+    })
+    .rpc();
+  return vaultTokenAccount;
+};
+
+// Challenge ids are per creator, and every suite creates as the admin.
+let challengeId = 1;
+
+// Create a challenge in the protocol mint with a 100-token entry fee.
+const createProtocolChallenge = async (
+  { mint, tokenProgram },
+  { startIn = 30, length = 60, maxParticipants = 2 } = {}
+) => {
+  const id = new BN(challengeId++);
+  const challenge = pda(Buffer.from("challenge"), admin.publicKey.toBuffer(), u64(id));
+  const challengeVault = getAssociatedTokenAddressSync(mint, challenge, true, tokenProgram);
+  const startTs = now() + startIn;
+  await program.methods
+    .createChallenge(id, {
+      metric: { steps: {} },
+      unit: { count: {} },
+      target: new BN(10_000),
+      startTs: new BN(startTs),
+      endTs: new BN(startTs + length),
+      entryFee: new BN(100),
+      maxParticipants,
+    })
+    .accountsPartial({ challenge, mint, challengeVault, creator: admin.publicKey, tokenProgram })
+    .rpc();
+  return { challenge, challengeVault };
+};
+
+// Pool ids are global PDAs, so every suite draws from one counter.
+let poolId = 100;
+const nextPoolId = () => new BN(poolId++);
+
+// Create and fund a reward pool paying in `mint`.
+const createPool = async ({
+  mint,
+  tokenProgram = TOKEN_PROGRAM_ID,
+  issuers,
+  budget = 1_000_000,
+  epochCap = budget,
+  fund = budget,
+}) => {
+  const id = nextPoolId();
+  const rewardPool = pda(Buffer.from("reward_pool"), id.toArrayLike(Buffer, "le", 8));
+  const poolVault = getAssociatedTokenAddressSync(mint, rewardPool, true, tokenProgram);
+  const ts = now();
+
+  await program.methods
+    .createRewardPool(id, {
+      budget: new BN(budget),
+      epochCap: new BN(epochCap),
+      startTs: new BN(ts - 60),
+      endTs: new BN(ts + 3_600),
+      issuers: issuers.map((k) => k.publicKey),
+    })
+    .accountsPartial({
+      config: configPda,
+      rewardPool,
+      mint,
+      poolVault,
+      admin: admin.publicKey,
+      tokenProgram,
+    })
+    .rpc();
+
+  if (fund > 0) {
+    const funderTokenAccount = await mintToOwner(mint, admin.publicKey, fund, tokenProgram);
+    await program.methods
+      .fundRewardPool(new BN(fund))
+      .accountsPartial({
+        rewardPool,
+        mint,
+        funder: admin.publicKey,
+        funderTokenAccount,
+        poolVault,
+        tokenProgram,
+      })
+      .rpc();
+  }
+  return { id, rewardPool, poolVault, mint, tokenProgram };
+};
+
+// Pass `stakeAccount` to apply the user's staking multiplier.
+const rewardUser = (
+  pool,
+  { user, issuer, amount, period, metric = { steps: {} }, stakeAccount = null }
+) =>
+  program.methods
+    .rewardUser(new BN(amount), metric, new BN(period), { manualGrant: {} })
+    .accountsPartial({
+      config: configPda,
+      rewardPool: pool.rewardPool,
+      mint: pool.mint,
+      user: user.publicKey,
+      issuer: issuer.publicKey,
+      userTokenAccount: getAssociatedTokenAddressSync(
+        pool.mint,
+        user.publicKey,
+        false,
+        pool.tokenProgram
+      ),
+      poolVault: pool.poolVault,
+      stakeAccount,
+      tokenProgram: pool.tokenProgram,
+    })
+    .signers([user, issuer])
+    .rpc();
+
+module.exports = {
+  ARWEAVE_ID,
+  BN,
+  DAY,
+  IPFS_CID,
+  DEFAULT_LIMITS,
+  addRecord,
+  admin,
+  airdrop,
+  balance,
+  configPda,
+  cpiEvents,
+  createGoal,
+  createPool,
+  createProtocolChallenge,
+  createTestMint,
+  dayStart,
+  ensureProtocol,
+  expectError,
+  fundVault,
+  goalInput,
+  goalPda,
+  grantAccess,
+  grantPda,
+  i64,
+  mintToOwner,
+  newProfile,
+  newUser,
+  now,
+  pda,
+  program,
+  profilePda,
+  programData,
+  protocolIssuer,
+  provider,
+  recordInput,
+  recordPda,
+  rewardUser,
+  setLimits,
+  u64,
+  vaultPda,
+  waitUntil,
+  web3,
+};
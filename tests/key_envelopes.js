@@ -0,0 +1,111 @@
+const { assert } = require("chai");
+const {
+  ARWEAVE_ID,
+  IPFS_CID,
+  addRecord,
+  expectError,
+  grantAccess,
+  newProfile,
+  pda,
+  program,
+  web3,
+} = require("./helpers");
+
+const envelopePda = (record, grantee) =>
+  pda(Buffer.from("key_envelope"), record.toBuffer(), grantee.toBuffer());
+
+describe("record key envelopes", () => {
+  const share = (user, profile, healthRecord, accessGrant, wrappedKey = Buffer.alloc(48, 1)) =>
+    program.methods
+      .shareRecordKey(Array(32).fill(2), wrappedKey)
+      .accountsPartial({
+        userProfile: profile,
+        healthRecord,
+        accessGrant,
+        authority: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+
+  const rotate = (user, profile, healthRecord, storageUri = `ipfs://${IPFS_CID}`) =>
+    program.methods
+      .rotateRecordKey(storageUri, Array(32).fill(3))
+      .accountsPartial({ userProfile: profile, healthRecord, authority: user.publicKey })
+      .signers([user])
+      .rpc();
+
+  const setup = async (scope) => {
+    const { user, profile } = await newProfile();
+    const healthRecord = await addRecord(user);
+    const grantee = web3.Keypair.generate().publicKey;
+    const accessGrant = await grantAccess(user, grantee, scope && { scope });
+    return { user, profile, healthRecord, grantee, accessGrant };
+  };
+
+  it("wraps the key for a grantee once per record", async () => {
+    const { user, profile, healthRecord, grantee, accessGrant } = await setup();
+    await share(user, profile, healthRecord, accessGrant);
+    await share(user, profile, healthRecord, accessGrant, Buffer.alloc(48, 5));
+
+    const envelope = await program.account.keyEnvelope.fetch(envelopePda(healthRecord, grantee));
+    assert.ok(envelope.record.equals(healthRecord));
+    assert.ok(envelope.grantee.equals(grantee));
+    assert.equal(envelope.keyVersion, 0);
+    assert.deepEqual([...envelope.wrappedKey], [...Buffer.alloc(48, 5)]);
+    // Record, grant and one envelope; re-sharing overwrites in place.
+    assert.equal((await program.account.userProfile.fetch(profile)).childCount, 3);
+  });
+
+  it("rejects malformed keys and records outside the grant", async () => {
+    const { user, profile, healthRecord, accessGrant } = await setup();
+    await expectError(
+      share(user, profile, healthRecord, accessGrant, Buffer.alloc(0)),
+      "InvalidWrappedKey"
+    );
+    await expectError(
+      share(user, profile, healthRecord, accessGrant, Buffer.alloc(81)),
+      "InvalidWrappedKey"
+    );
+
+    const narrow = await setup({ metrics: [{ heartRate: {} }], recordIndices: [] });
+    await expectError(
+      share(narrow.user, narrow.profile, narrow.healthRecord, narrow.accessGrant),
+      "RecordNotInGrantScope"
+    );
+  });
+
+  it("rotates the record key and leaves old envelopes stale", async () => {
+    const { user, profile, healthRecord, grantee, accessGrant } = await setup();
+    await share(user, profile, healthRecord, accessGrant);
+    await expectError(rotate(user, profile, healthRecord, ARWEAVE_ID), "InvalidStorageUri");
+
+    await rotate(user, profile, healthRecord);
+    const record = await program.account.healthRecord.fetch(healthRecord);
+    assert.equal(record.keyVersion, 1);
+    assert.equal(record.storageUri, `ipfs://${IPFS_CID}`);
+    const envelope = envelopePda(healthRecord, grantee);
+    assert.equal((await program.account.keyEnvelope.fetch(envelope)).keyVersion, 0);
+
+    await share(user, profile, healthRecord, accessGrant);
+    assert.equal((await program.account.keyEnvelope.fetch(envelope)).keyVersion, 1);
+  });
+
+  it("closes an envelope and releases its child slot", async () => {
+    const { user, profile, healthRecord, grantee, accessGrant } = await setup();
+    await share(user, profile, healthRecord, accessGrant);
+    const keyEnvelope = envelopePda(healthRecord, grantee);
+
+    const other = await newProfile();
+    const close = (owner, userProfile) =>
+      program.methods
+        .closeKeyEnvelope()
+        .accountsPartial({ userProfile, keyEnvelope, authority: owner.publicKey })
+        .signers([owner])
+        .rpc();
+    await expectError(close(other.user, other.profile), "ChildAccountMismatch");
+
+    await close(user, profile);
+    assert.isNull(await program.account.keyEnvelope.fetchNullable(keyEnvelope));
+    assert.equal((await program.account.userProfile.fetch(profile)).childCount, 2);
+  });
+});
@@ -0,0 +1,153 @@
+const { assert } = require("chai");
+const { getAssociatedTokenAddressSync } = require("@solana/spl-token");
+const {
+  BN,
+  admin,
+  balance,
+  configPda,
+  createPool,
+  createProtocolChallenge,
+  dayStart,
+  ensureProtocol,
+  expectError,
+  fundVault,
+  newUser,
+  now,
+  pda,
+  program,
+  protocolIssuer,
+  rewardUser,
+  web3,
+} = require("./helpers");
+
+describe("pause and emergency withdrawal", () => {
+  let protocol;
+  let treasury;
+  let vaultTokenAccount;
+
+  before(async () => {
+    protocol = await ensureProtocol();
+    treasury = (await program.account.protocolConfig.fetch(configPda)).treasury;
+    vaultTokenAccount = await fundVault(protocol, 1_000);
+  });
+
+  // The guardian defaults to the admin.
+  const setPaused = (paused, guardian = admin) =>
+    program.methods
+      .setPaused(paused)
+      .accounts({ guardian: guardian.publicKey })
+      .signers(guardian.payer ? [] : [guardian])
+      .rpc();
+
+  // Leave the protocol running for the other suites whatever happens here.
+  afterEach(() => setPaused(false));
+
+  const withdraw = (amount, signer = admin) =>
+    program.methods
+      .emergencyWithdraw(new BN(amount))
+      .accountsPartial({
+        mint: protocol.mint,
+        admin: signer.publicKey,
+        treasury,
+        treasuryTokenAccount: getAssociatedTokenAddressSync(
+          protocol.mint,
+          treasury,
+          true,
+          protocol.tokenProgram
+        ),
+        vaultTokenAccount,
+        tokenProgram: protocol.tokenProgram,
+      })
+      .signers(signer.payer ? [] : [signer])
+      .rpc();
+
+  it("only lets the guardian pause", async () => {
+    const intruder = await newUser();
+    await expectError(setPaused(true, intruder), "Unauthorized");
+    assert.isFalse((await program.account.protocolConfig.fetch(configPda)).paused);
+  });
+
+  it("blocks payouts while paused and resumes them afterwards", async () => {
+    const issuer = await protocolIssuer();
+    const user = await newUser();
+    const pool = await createPool({ ...protocol, issuers: [issuer] });
+
+    await setPaused(true);
+    assert.isTrue((await program.account.protocolConfig.fetch(configPda)).paused);
+    await expectError(
+      rewardUser(pool, { user, issuer, amount: 10, period: dayStart() }),
+      "ProtocolPaused"
+    );
+
+    await setPaused(false);
+    await rewardUser(pool, { user, issuer, amount: 10, period: dayStart() });
+  });
+
+  it("blocks vesting grants and challenge sweeps while paused", async () => {
+    const { mint, tokenProgram } = protocol;
+    const beneficiary = web3.Keypair.generate().publicKey;
+    const id = new BN(1);
+    const vestingSchedule = pda(
+      Buffer.from("vesting"),
+      beneficiary.toBuffer(),
+      id.toArrayLike(Buffer, "le", 8)
+    );
+    const { challenge, challengeVault } = await createProtocolChallenge(protocol);
+
+    await setPaused(true);
+    await expectError(
+      program.methods
+        .createVesting(id, beneficiary, {
+          totalAmount: new BN(100),
+          startTs: new BN(now()),
+          cliffTs: new BN(now()),
+          duration: new BN(3_600),
+        })
+        .accountsPartial({
+          mint,
+          admin: admin.publicKey,
+          vestingSchedule,
+          vestingVault: getAssociatedTokenAddressSync(mint, vestingSchedule, true, tokenProgram),
+          vaultTokenAccount,
+          tokenProgram,
+        })
+        .rpc(),
+      "ProtocolPaused"
+    );
+    await expectError(
+      program.methods
+        .closeChallenge()
+        .accountsPartial({
+          challenge,
+          mint,
+          challengeVault,
+          creator: admin.publicKey,
+          tokenProgram,
+        })
+        .rpc(),
+      "ProtocolPaused"
+    );
+  });
+
+  it("drains the vault to the treasury even while paused", async () => {
+    const treasuryAta = getAssociatedTokenAddressSync(
+      protocol.mint,
+      treasury,
+      true,
+      protocol.tokenProgram
+    );
+    const before = await program.account.protocolConfig.fetch(configPda);
+    await setPaused(true);
+
+    await withdraw(250);
+
+    const after = await program.account.protocolConfig.fetch(configPda);
+    assert.equal(after.totalWithdrawn.sub(before.totalWithdrawn).toString(), "250");
+    assert.isAtLeast(Number(await balance(treasuryAta, protocol.tokenProgram)), 250);
+  });
+
+  it("rejects empty withdrawals and anyone but the admin", async () => {
+    await expectError(withdraw(0), "InvalidAmount");
+    await expectError(withdraw(1, await newUser()), "Unauthorized");
+  });
+});
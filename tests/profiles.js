@@ -0,0 +1,141 @@
+const { assert } = require("chai");
+const {
+  ARWEAVE_ID,
+  IPFS_CID,
+  addRecord,
+  cpiEvents,
+  createPool,
+  dayStart,
+  ensureProtocol,
+  expectError,
+  newProfile,
+  newUser,
+  profilePda,
+  pda,
+  program,
+  protocolIssuer,
+  rewardUser,
+} = require("./helpers");
+
+const init = (user, arweaveHash, storage) =>
+  program.methods
+    .initializeUserProfile(arweaveHash, storage)
+    .accounts({ authority: user.publicKey })
+    .signers([user])
+    .rpc();
+
+const update = (user, arweaveHash, storage) =>
+  program.methods
+    .updateUserProfile(arweaveHash, storage)
+    .accounts({ authority: user.publicKey })
+    .signers([user])
+    .rpc();
+
+describe("initialize_user_profile", () => {
+  it("stores a valid Arweave or IPFS pointer", async () => {
+    const user = await newUser();
+    await init(user, IPFS_CID, { ipfs: {} });
+    const account = await program.account.userProfile.fetch(profilePda(user.publicKey));
+    assert.ok(account.authority.equals(user.publicKey));
+    assert.equal(account.arweaveHash, IPFS_CID);
+    assert.deepEqual(account.storage, { ipfs: {} });
+  });
+
+  it("rejects malformed storage pointers", async () => {
+    const user = await newUser();
+    await expectError(init(user, "b".repeat(65), { ipfs: {} }), "StorageIdTooLong");
+    await expectError(init(user, ARWEAVE_ID.slice(1), { arweave: {} }), "InvalidArweaveTxId");
+    await expectError(init(user, ARWEAVE_ID, { ipfs: {} }), "InvalidIpfsCid");
+    assert.isNull(await program.account.userProfile.fetchNullable(profilePda(user.publicKey)));
+  });
+});
+
+describe("update_user_profile", () => {
+  it("replaces only the fields that are passed", async () => {
+    const { user, profile } = await newProfile();
+    const newId = "B".repeat(43);
+
+    await update(user, newId, null);
+    let account = await program.account.userProfile.fetch(profile);
+    assert.equal(account.arweaveHash, newId);
+    assert.deepEqual(account.storage, { arweave: {} });
+
+    await update(user, IPFS_CID, { ipfs: {} });
+    account = await program.account.userProfile.fetch(profile);
+    assert.equal(account.arweaveHash, IPFS_CID);
+    assert.deepEqual(account.storage, { ipfs: {} });
+  });
+
+  it("re-validates the kept pointer against a new storage kind", async () => {
+    const { user } = await newProfile();
+    await expectError(update(user, null, { ipfs: {} }), "InvalidIpfsCid");
+  });
+
+  it("only updates the signer's own profile", async () => {
+    await newProfile();
+    const stranger = await newUser();
+    // The profile PDA is derived from the signer, so a stranger can only
+    // address their own (nonexistent) profile.
+    await expectError(update(stranger, ARWEAVE_ID, null), "AccountNotInitialized");
+    assert.isNull(await program.account.userProfile.fetchNullable(profilePda(stranger.publicKey)));
+  });
+});
+
+describe("close_user_profile", () => {
+  const close = (user, children = []) =>
+    program.methods
+      .closeUserProfile()
+      .accounts({ authority: user.publicKey })
+      .remainingAccounts(children.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+      .signers([user])
+      .rpc();
+
+  it("closes a profile without children", async () => {
+    const { user, profile } = await newProfile();
+    await close(user);
+    assert.isNull(await program.account.userProfile.fetchNullable(profile));
+  });
+
+  it("requires every child account to be closed with it", async () => {
+    const { user, profile } = await newProfile();
+    const first = await addRecord(user);
+    const second = await addRecord(user, { storageUri: `ipfs://${IPFS_CID}` });
+    assert.equal((await program.account.userProfile.fetch(profile)).childCount, 2);
+
+    await expectError(close(user, [first]), "ProfileHasOpenAccounts");
+    const signature = await close(user, [first, second]);
+    const [{ data: deleted }] = (await cpiEvents(signature)).filter(
+      (e) => e.name === "profileDeleted"
+    );
+    // The purge service learns every blob to erase from the event alone.
+    assert.equal(deleted.arweaveHash, ARWEAVE_ID);
+    assert.deepEqual(deleted.recordUris, [`ar://${ARWEAVE_ID}`, `ipfs://${IPFS_CID}`]);
+    assert.equal(deleted.closedAccounts, 2);
+    assert.isNull(await program.account.userProfile.fetchNullable(profile));
+    assert.isNull(await program.account.healthRecord.fetchNullable(first));
+    assert.isNull(await program.account.healthRecord.fetchNullable(second));
+  });
+
+  it("rejects another profile's children", async () => {
+    const { user } = await newProfile();
+    const other = await newProfile();
+    const foreign = await addRecord(other.user);
+    await expectError(close(user, [foreign]), "ChildAccountMismatch");
+  });
+
+  it("rejects accounts the program does not own", async () => {
+    const { user } = await newProfile();
+    await expectError(close(user, [user.publicKey]), "InvalidChildAccount");
+  });
+
+  it("keeps the reward ledger while its epoch window is open", async () => {
+    const protocol = await ensureProtocol();
+    const issuer = await protocolIssuer();
+    const pool = await createPool({ ...protocol, issuers: [issuer] });
+    const { user } = await newProfile();
+    await rewardUser(pool, { user, issuer, amount: 10, period: dayStart() });
+
+    const ledger = pda(Buffer.from("reward_ledger"), user.publicKey.toBuffer());
+    await expectError(close(user, [ledger]), "RewardLedgerWindowOpen");
+  });
+});
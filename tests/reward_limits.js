@@ -0,0 +1,55 @@
+const { assert } = require("chai");
+const {
+  BN,
+  DAY,
+  configPda,
+  createPool,
+  dayStart,
+  ensureProtocol,
+  expectError,
+  newUser,
+  program,
+  protocolIssuer,
+  rewardUser,
+  setLimits,
+} = require("./helpers");
+
+describe("reward limits", () => {
+  let protocol;
+  before(async () => {
+    protocol = await ensureProtocol();
+  });
+  after(() => setLimits({}));
+
+  it("rejects widening the claim horizon", async () => {
+    const { limits } = await program.account.protocolConfig.fetch(configPda);
+    await expectError(
+      setLimits({ claimHorizon: limits.claimHorizon.add(new BN(DAY)) }),
+      "ClaimHorizonIncreased"
+    );
+    await setLimits({ claimHorizon: limits.claimHorizon });
+  });
+
+  it("accepts cap changes that keep the horizon", async () => {
+    await setLimits({ userEpochCap: new BN(5_000) });
+    const { limits } = await program.account.protocolConfig.fetch(configPda);
+    assert.equal(limits.userEpochCap.toString(), "5000");
+  });
+
+  it("stops paying every user once the global epoch cap is reached", async () => {
+    const issuer = await protocolIssuer();
+    const pool = await createPool({ ...protocol, issuers: [issuer] });
+    const config = await program.account.protocolConfig.fetch(configPda);
+    const spent = config.epochStart.eqn(dayStart()) ? config.epochDistributed : new BN(0);
+    await setLimits({ globalEpochCap: spent.addn(50) });
+
+    const alice = await newUser();
+    const bob = await newUser();
+    await rewardUser(pool, { user: alice, issuer, amount: 30, period: dayStart() });
+    await expectError(
+      rewardUser(pool, { user: bob, issuer, amount: 30, period: dayStart() }),
+      "GlobalRewardCapExceeded"
+    );
+    await rewardUser(pool, { user: bob, issuer, amount: 20, period: dayStart() });
+  });
+});
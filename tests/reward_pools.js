@@ -0,0 +1,146 @@
+const { assert } = require("chai");
+const { getAssociatedTokenAddressSync } = require("@solana/spl-token");
+const {
+  BN,
+  DAY,
+  admin,
+  balance,
+  configPda,
+  createPool,
+  createTestMint,
+  dayStart,
+  ensureProtocol,
+  expectError,
+  newUser,
+  pda,
+  program,
+  protocolIssuer,
+  rewardUser,
+  setLimits,
+  web3,
+} = require("./helpers");
+
+describe("reward pool caps", () => {
+  let protocol;
+  before(async () => {
+    protocol = await ensureProtocol();
+  });
+  after(() => setLimits({}));
+
+  it("charges protocol-mint pool payouts to the per-user epoch cap", async () => {
+    await setLimits({ userEpochCap: new BN(100) });
+    const issuer = await protocolIssuer();
+    const user = await newUser();
+    const pool = await createPool({ ...protocol, issuers: [issuer] });
+    const before = await program.account.protocolConfig.fetch(configPda);
+
+    await rewardUser(pool, { user, issuer, amount: 80, period: dayStart() });
+    await expectError(
+      rewardUser(pool, { user, issuer, amount: 80, period: dayStart() - DAY }),
+      "UserRewardCapExceeded"
+    );
+
+    const after = await program.account.protocolConfig.fetch(configPda);
+    assert.equal(after.totalDistributed.sub(before.totalDistributed).toString(), "80");
+  });
+
+  it("bounds every pool by its own epoch cap", async () => {
+    const issuer = await protocolIssuer();
+    const user = await newUser();
+    const mint = await createTestMint();
+    const pool = await createPool({ mint, issuers: [issuer], budget: 1_000, epochCap: 100 });
+
+    await rewardUser(pool, { user, issuer, amount: 60, period: dayStart() });
+    await expectError(
+      rewardUser(pool, { user, issuer, amount: 60, period: dayStart() - DAY }),
+      "PoolEpochCapExceeded"
+    );
+  });
+
+  it("rejects a pool issuer that is not a protocol issuer", async () => {
+    const outsider = web3.Keypair.generate();
+    const user = await newUser();
+    const mint = await createTestMint();
+    const pool = await createPool({ mint, issuers: [outsider] });
+
+    await expectError(
+      rewardUser(pool, { user, issuer: outsider, amount: 10, period: dayStart() }),
+      "UnauthorizedIssuer"
+    );
+  });
+
+  it("only pays periods aligned to a day boundary", async () => {
+    const issuer = await protocolIssuer();
+    const user = await newUser();
+    const mint = await createTestMint();
+    const pool = await createPool({ mint, issuers: [issuer] });
+
+    await expectError(
+      rewardUser(pool, { user, issuer, amount: 10, period: dayStart() - 1 }),
+      "MisalignedPeriod"
+    );
+    await rewardUser(pool, { user, issuer, amount: 10, period: dayStart() });
+  });
+
+  it("credits protocol-mint payouts to the user's reward history", async () => {
+    const issuer = await protocolIssuer();
+    const user = await newUser();
+    const pool = await createPool({ ...protocol, issuers: [issuer] });
+
+    await rewardUser(pool, { user, issuer, amount: 30, period: dayStart() });
+    await rewardUser(pool, { user, issuer, amount: 12, period: dayStart() - DAY });
+
+    const ledger = await program.account.rewardLedger.fetch(
+      pda(Buffer.from("reward_ledger"), user.publicKey.toBuffer())
+    );
+    assert.ok(ledger.authority.equals(user.publicKey));
+    // `RewardReason::ManualGrant`, the reason `rewardUser` pays under.
+    assert.equal(ledger.earnedByReason[5].toString(), "42");
+    assert.equal(ledger.earnedThisMonth.toString(), "42");
+    const month = new Date(ledger.monthStart.toNumber() * 1000);
+    assert.equal(month.getUTCDate(), 1);
+    assert.equal(month.getUTCHours(), 0);
+  });
+
+  describe("emergency_withdraw_pool", () => {
+    const withdraw = (pool, amount, signer) =>
+      program.methods
+        .emergencyWithdrawPool(new BN(amount))
+        .accountsPartial({
+          config: configPda,
+          rewardPool: pool.rewardPool,
+          mint: pool.mint,
+          admin: signer.publicKey,
+          treasury,
+          treasuryTokenAccount: getAssociatedTokenAddressSync(pool.mint, treasury),
+          poolVault: pool.poolVault,
+        })
+        .signers(signer.payer ? [] : [signer])
+        .rpc();
+
+    let treasury;
+    before(async () => {
+      treasury = (await program.account.protocolConfig.fetch(configPda)).treasury;
+    });
+
+    it("drains a pool vault to the treasury", async () => {
+      const mint = await createTestMint();
+      const pool = await createPool({ mint, issuers: [], budget: 500 });
+
+      await withdraw(pool, 300, admin);
+
+      assert.equal(await balance(pool.poolVault), "200");
+      assert.equal(await balance(getAssociatedTokenAddressSync(mint, treasury)), "300");
+      const account = await program.account.rewardPool.fetch(pool.rewardPool);
+      assert.equal(account.totalWithdrawn.toString(), "300");
+    });
+
+    it("rejects anyone but the admin", async () => {
+      const mint = await createTestMint();
+      const pool = await createPool({ mint, issuers: [], budget: 500 });
+      const intruder = await newUser();
+
+      await expectError(withdraw(pool, 100, intruder), "Unauthorized");
+    });
+  });
+});
@@ -0,0 +1,169 @@
+const { assert } = require("chai");
+const { getAssociatedTokenAddressSync } = require("@solana/spl-token");
+const {
+  BN,
+  admin,
+  balance,
+  createPool,
+  createTestMint,
+  dayStart,
+  ensureProtocol,
+  expectError,
+  mintToOwner,
+  newUser,
+  pda,
+  program,
+  protocolIssuer,
+  provider,
+  rewardUser,
+} = require("./helpers");
+
+const sleep = (ms) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("staking rewards", () => {
+  const stakingPool = pda(Buffer.from("staking_pool"));
+  let protocol;
+  let rewardVault;
+
+  before(async () => {
+    protocol = await ensureProtocol();
+    const { mint, tokenProgram } = protocol;
+    rewardVault = getAssociatedTokenAddressSync(mint, stakingPool, true, tokenProgram);
+    if (!(await program.account.stakingPool.fetchNullable(stakingPool))) {
+      await program.methods
+        .initializeStakingPool(new BN(1))
+        .accountsPartial({ mint, rewardVault, admin: admin.publicKey, tokenProgram })
+        .rpc();
+    }
+  });
+
+  const fund = async (amount) => {
+    const { mint, tokenProgram } = protocol;
+    const funderTokenAccount = await mintToOwner(mint, admin.publicKey, amount, tokenProgram);
+    await program.methods
+      .fundStakingRewards(new BN(amount))
+      .accountsPartial({
+        mint,
+        funder: admin.publicKey,
+        funderTokenAccount,
+        rewardVault,
+        tokenProgram,
+      })
+      .rpc();
+  };
+
+  const stake = async (owner, amount) => {
+    const { mint, tokenProgram } = protocol;
+    const ownerTokenAccount = await mintToOwner(mint, owner.publicKey, amount, tokenProgram);
+    await program.methods
+      .stake(new BN(amount))
+      .accountsPartial({ mint, owner: owner.publicKey, ownerTokenAccount, tokenProgram })
+      .signers([owner])
+      .rpc();
+  };
+
+  const claim = (owner) =>
+    program.methods
+      .claimStakingRewards()
+      .accountsPartial({
+        mint: protocol.mint,
+        owner: owner.publicKey,
+        rewardVault,
+        tokenProgram: protocol.tokenProgram,
+      })
+      .signers([owner])
+      .rpc();
+
+  it("tracks deposits into the staking reward vault", async () => {
+    const before = await program.account.stakingPool.fetch(stakingPool);
+    const vaultBefore = BigInt(await balance(rewardVault, protocol.tokenProgram));
+
+    await fund(1_000);
+
+    const after = await program.account.stakingPool.fetch(stakingPool);
+    assert.equal(after.totalFunded.sub(before.totalFunded).toString(), "1000");
+    assert.equal(BigInt(await balance(rewardVault, protocol.tokenProgram)) - vaultBefore, 1_000n);
+  });
+
+  it("pays accrued rewards from the reward vault only", async () => {
+    await fund(1_000);
+    const owner = await newUser();
+    await stake(owner, 1_000_000);
+    await sleep(2_000);
+
+    const vaultBefore = BigInt(await balance(rewardVault, protocol.tokenProgram));
+    await claim(owner);
+
+    const ownerAta = getAssociatedTokenAddressSync(
+      protocol.mint,
+      owner.publicKey,
+      false,
+      protocol.tokenProgram
+    );
+    const paid = BigInt(await balance(ownerAta, protocol.tokenProgram));
+    assert.isTrue(paid > 0n);
+    assert.equal(vaultBefore - BigInt(await balance(rewardVault, protocol.tokenProgram)), paid);
+
+    const pool = await program.account.stakingPool.fetch(stakingPool);
+    assert.isTrue(pool.totalEmitted.lte(pool.totalFunded));
+  });
+
+  it("rejects a claim with nothing accrued", async () => {
+    // A single token among a large stake earns less than one unit per second.
+    const owner = await newUser();
+    await stake(owner, 1);
+    await expectError(claim(owner), "NothingToClaim");
+  });
+
+  describe("reward multiplier", () => {
+    const params = (maxBonusBps) => ({
+      cooldown: new BN(0),
+      maxBonusBps: new BN(maxBonusBps),
+      fullBonusAmount: new BN(1_000),
+      fullBonusDuration: new BN(1),
+    });
+    const setParams = (maxBonusBps) =>
+      program.methods
+        .setStakingParams(params(maxBonusBps))
+        .accounts({ admin: admin.publicKey })
+        .rpc();
+
+    let owner;
+    let stakeAccount;
+    before(async () => {
+      await setParams(10_000);
+      owner = await newUser();
+      await stake(owner, 1_000);
+      stakeAccount = pda(Buffer.from("stake"), owner.publicKey.toBuffer());
+      await sleep(2_000);
+    });
+    after(() => setParams(0));
+
+    const paidBy = async (pool) => {
+      const ata = getAssociatedTokenAddressSync(
+        pool.mint,
+        owner.publicKey,
+        false,
+        pool.tokenProgram
+      );
+      const before = (await provider.connection.getAccountInfo(ata))
+        ? BigInt(await balance(ata, pool.tokenProgram))
+        : 0n;
+      const issuer = await protocolIssuer();
+      await rewardUser(pool, { user: owner, issuer, amount: 100, period: dayStart(), stakeAccount });
+      return BigInt(await balance(ata, pool.tokenProgram)) - before;
+    };
+
+    it("boosts pools paying in the protocol mint", async () => {
+      const issuer = await protocolIssuer();
+      const pool = await createPool({ ...protocol, issuers: [issuer] });
+      assert.equal(await paidBy(pool), 200n);
+    });
+
+    it("does not boost pools paying in another mint", async () => {
+      const issuer = await protocolIssuer();
+      const pool = await createPool({ mint: await createTestMint(), issuers: [issuer] });
+      assert.equal(await paidBy(pool), 100n);
+    });
+  });
+});
@@ -0,0 +1,47 @@
+const { assert } = require("chai");
+const {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} = require("@solana/spl-token");
+const {
+  balance,
+  createPool,
+  createTestMint,
+  dayStart,
+  ensureProtocol,
+  newUser,
+  program,
+  protocolIssuer,
+  rewardUser,
+} = require("./helpers");
+
+// Reward pools pay through `transfer_checked`, so the same deployment must
+// work with mints owned by either token program.
+describe("reward pools across token programs", () => {
+  before(ensureProtocol);
+
+  const cases = [
+    { name: "Token", tokenProgram: TOKEN_PROGRAM_ID },
+    { name: "Token-2022", tokenProgram: TOKEN_2022_PROGRAM_ID },
+  ];
+
+  for (const { name, tokenProgram } of cases) {
+    it(`funds a pool and pays a reward with a ${name} mint`, async () => {
+      const issuer = await protocolIssuer();
+      const user = await newUser();
+      const mint = await createTestMint(tokenProgram);
+      const pool = await createPool({ mint, tokenProgram, issuers: [issuer], budget: 500_000 });
+
+      await rewardUser(pool, { user, issuer, amount: 250_000, period: dayStart() });
+
+      const userAta = getAssociatedTokenAddressSync(mint, user.publicKey, false, tokenProgram);
+      assert.equal(await balance(userAta, tokenProgram), "250000");
+      assert.equal(await balance(pool.poolVault, tokenProgram), "250000");
+
+      const account = await program.account.rewardPool.fetch(pool.rewardPool);
+      assert.equal(account.distributed.toString(), "250000");
+      assert.equal(account.totalFunded.toString(), "500000");
+    });
+  }
+});
@@ -0,0 +1,157 @@
+const { assert } = require("chai");
+const { getAssociatedTokenAddressSync } = require("@solana/spl-token");
+const {
+  BN,
+  DAY,
+  admin,
+  balance,
+  configPda,
+  dayStart,
+  ensureProtocol,
+  expectError,
+  fundVault,
+  newUser,
+  pda,
+  program,
+  vaultPda,
+  web3,
+} = require("./helpers");
+
+const ACTIVITY_ATTESTATION_DOMAIN = Buffer.from("healthkey:activity:v1");
+
+// The vault pays in the protocol mint, whichever token program owns it
+// (see HEALTH_TOKEN_PROGRAM in helpers.js).
+describe("protocol vault", () => {
+  const oracle = web3.Keypair.generate();
+  let protocol;
+  let vaultTokenAccount;
+
+  before(async () => {
+    protocol = await ensureProtocol();
+    vaultTokenAccount = getAssociatedTokenAddressSync(
+      protocol.mint,
+      vaultPda,
+      true,
+      protocol.tokenProgram
+    );
+    await program.methods
+      .addOracle(oracle.publicKey)
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    await program.methods
+      .setRewardTable([
+        { metric: { steps: {} }, unit: { count: {} }, minValue: new BN(5_000), reward: new BN(40) },
+        { metric: { steps: {} }, unit: { count: {} }, minValue: new BN(10_000), reward: new BN(75) },
+      ])
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+  });
+
+  after(() =>
+    program.methods
+      .removeOracle(oracle.publicKey)
+      .accounts({ admin: admin.publicKey })
+      .rpc()
+  );
+
+  const fund = (amount) => fundVault(protocol, amount);
+
+  const claim = (user, attestation, signer = oracle) => {
+    const message = Buffer.concat([
+      ACTIVITY_ATTESTATION_DOMAIN,
+      program.coder.types.encode("activityAttestation", attestation),
+    ]);
+    const { mint, tokenProgram } = protocol;
+    return program.methods
+      .claimAttestedReward(attestation)
+      .accountsPartial({
+        mint,
+        user: user.publicKey,
+        userTokenAccount: getAssociatedTokenAddressSync(mint, user.publicKey, false, tokenProgram),
+        vaultTokenAccount,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenProgram,
+      })
+      .preInstructions([
+        web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: signer.secretKey,
+          message,
+        }),
+      ])
+      .signers([user])
+      .rpc();
+  };
+
+  const attestationFor = (user, value, period = dayStart()) => ({
+    user: user.publicKey,
+    metric: { steps: {} },
+    unit: { count: {} },
+    value: new BN(value),
+    period: new BN(period),
+  });
+
+  it("records deposits", async () => {
+    const before = await program.account.protocolConfig.fetch(configPda);
+    await fund(10_000);
+    const after = await program.account.protocolConfig.fetch(configPda);
+    assert.equal(after.totalFunded.sub(before.totalFunded).toString(), "10000");
+  });
+
+  it("rejects empty deposits", async () => {
+    await expectError(fund(0), "InvalidAmount");
+  });
+
+  it("counts attested payouts in total_distributed", async () => {
+    await fund(1_000);
+    const user = await newUser();
+    const before = await program.account.protocolConfig.fetch(configPda);
+
+    await claim(user, attestationFor(user, 5_000));
+    await claim(user, attestationFor(user, 10_000, dayStart() - DAY));
+
+    const after = await program.account.protocolConfig.fetch(configPda);
+    assert.equal(after.totalDistributed.sub(before.totalDistributed).toString(), "115");
+    assert.equal(after.totalFunded.sub(before.totalFunded).toString(), "1000");
+    assert.equal(after.totalWithdrawn.toString(), before.totalWithdrawn.toString());
+  });
+
+  it("pays the highest tier reached for an oracle attestation", async () => {
+    await fund(1_000);
+    const user = await newUser();
+    const vaultBefore = BigInt(await balance(vaultTokenAccount, protocol.tokenProgram));
+
+    await claim(user, attestationFor(user, 12_000));
+
+    const userAta = getAssociatedTokenAddressSync(
+      protocol.mint,
+      user.publicKey,
+      false,
+      protocol.tokenProgram
+    );
+    assert.equal(await balance(userAta, protocol.tokenProgram), "75");
+    assert.equal(
+      vaultBefore - BigInt(await balance(vaultTokenAccount, protocol.tokenProgram)),
+      75n
+    );
+    const ledger = await program.account.rewardLedger.fetch(
+      pda(Buffer.from("reward_ledger"), user.publicKey.toBuffer())
+    );
+    assert.equal(ledger.earnedThisMonth.toString(), "75");
+  });
+
+  it("pays each period once", async () => {
+    await fund(1_000);
+    const user = await newUser();
+    await claim(user, attestationFor(user, 6_000));
+    await expectError(claim(user, attestationFor(user, 6_000)), "AlreadyClaimed");
+    await claim(user, attestationFor(user, 6_000, dayStart() - DAY));
+  });
+
+  it("rejects attestations from unregistered keys", async () => {
+    const user = await newUser();
+    await expectError(
+      claim(user, attestationFor(user, 6_000), web3.Keypair.generate()),
+      "UnknownOracle"
+    );
+  });
+});